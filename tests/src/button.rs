@@ -0,0 +1,55 @@
+use std::{cell::Cell, rc::Rc};
+
+use turse::{
+    ratatui::crossterm::event::{KeyCode, MouseButton, MouseEventKind},
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, mouse, render};
+
+fn buttons(clicks: Rc<Cell<u32>>) -> impl Fn() -> Element {
+    move || {
+        let save = clicks.clone();
+        let delete = clicks.clone();
+        trs! {
+            block {
+                button { disabled: true, onclick: move |_| delete.set(delete.get() + 100), "Delete" }
+                button { onclick: move |_| save.set(save.get() + 1), "Save" }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_button_keyboard_activation() {
+    let clicks = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(buttons(clicks.clone()));
+    let screen = render(&mut runtime, 12, 2);
+    assert_eq!(screen[0].trim_end(), "[ Delete ]");
+    assert_eq!(screen[1].trim_end(), "[ Save ]");
+    assert_eq!(runtime.focused(), Some("root/1"));
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    runtime.handle_event(&key(KeyCode::Char('x')));
+    assert_eq!(clicks.get(), 2);
+}
+
+#[test]
+fn test_button_mouse_click() {
+    let clicks = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(buttons(clicks.clone()));
+    render(&mut runtime, 12, 2);
+
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 2, 0));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 2, 0));
+    assert_eq!(clicks.get(), 0);
+
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 2, 1));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 2, 1));
+    assert_eq!(clicks.get(), 1);
+
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 2, 1));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 11, 0));
+    assert_eq!(clicks.get(), 1);
+}
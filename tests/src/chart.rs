@@ -0,0 +1,71 @@
+use turse::{
+    ratatui::{buffer::Buffer, layout::Rect, style::Color},
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::render;
+
+#[test]
+fn test_sparkline() {
+    let mut runtime = Runtime::new(|| {
+        trs! { sparkline { data: [0, 2, 4, 8] } }
+    });
+    let screen = render(&mut runtime, 4, 1);
+    assert_eq!(screen[0], " ▂▄█");
+}
+
+#[test]
+fn test_sparkline_keeps_latest_values() {
+    let values: Vec<f64> = (0..20).map(|i| i as f64).collect();
+    let mut runtime = Runtime::new(move || {
+        trs! { sparkline { data: &values, max: 19 } }
+    });
+    let screen = render(&mut runtime, 4, 1);
+    assert_eq!(screen[0], "▆▇▇█");
+}
+
+#[test]
+fn test_barchart_labels_and_values() {
+    let mut runtime = Runtime::new(|| {
+        trs! { barchart { data: [1, 2], labels: "a, b", height: 4 } }
+    });
+    let screen = render(&mut runtime, 8, 4);
+    assert_eq!(screen[3], " a   b  ");
+    assert_eq!(screen[2], "█1█ █2█ ");
+    assert_eq!(screen[0], "    ███ ");
+}
+
+#[test]
+fn test_linechart_axes_and_legend() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            linechart { height: 12,
+                series { name: "cpu", data: [1, 3, 2], color: "red" }
+                series { name: "mem", data: [2.5, 2.5, 2.5] }
+            }
+        }
+    });
+    let area = Rect::new(0, 0, 30, 12);
+    let mut buf = Buffer::empty(area);
+    runtime.render(area, &mut buf);
+    let screen: Vec<String> = (0..12)
+        .map(|y| (0..30).map(|x| buf[(x, y)].symbol()).collect())
+        .collect();
+    assert!(screen[0].starts_with("3"));
+    assert!(screen.iter().any(|line| line.contains("cpu")));
+    assert!(screen.iter().any(|line| line.contains("mem")));
+    assert!(screen[11].starts_with(" 0"));
+    assert!(screen[11].ends_with("2"));
+    let braille = (0..12)
+        .flat_map(|y| (0..30).map(move |x| (x, y)))
+        .filter(|&pos| {
+            buf[pos]
+                .symbol()
+                .chars()
+                .any(|c| ('\u{2800}'..='\u{28ff}').contains(&c))
+        })
+        .map(|pos| buf[pos].fg)
+        .collect::<Vec<_>>();
+    assert!(braille.contains(&Color::Red));
+    assert!(braille.contains(&Color::Magenta));
+}
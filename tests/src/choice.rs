@@ -0,0 +1,121 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{ratatui::crossterm::event::KeyCode, trs, AttrValue, Element, Event, Node, Runtime};
+
+use crate::support::{key, render};
+
+#[test]
+fn test_checkbox_and_toggle() {
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let on_checkbox = changes.clone();
+    let on_toggle = changes.clone();
+    let mut runtime = Runtime::new(move || {
+        let on_checkbox = on_checkbox.clone();
+        let on_toggle = on_toggle.clone();
+        trs! {
+            block {
+                checkbox {
+                    onchange: move |e| on_checkbox.borrow_mut().push(e.clone()),
+                    "Enable logging"
+                }
+                toggle {
+                    checked: true,
+                    onchange: move |e| on_toggle.borrow_mut().push(e.clone()),
+                    "Dark mode"
+                }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 24, 2);
+    assert_eq!(screen[0].trim_end(), "[ ] Enable logging");
+    assert_eq!(screen[1].trim_end(), "[on ] Dark mode");
+
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(
+        *changes.borrow(),
+        vec![
+            Event::Change(AttrValue::Bool(true)),
+            Event::Change(AttrValue::Bool(false))
+        ]
+    );
+
+    let screen = render(&mut runtime, 24, 2);
+    assert_eq!(screen[0].trim_end(), "[x] Enable logging");
+    assert_eq!(screen[1].trim_end(), "[off] Dark mode");
+}
+
+#[test]
+fn test_checked_binding() {
+    let checked = Rc::new(RefCell::new(false));
+    let app_checked = checked.clone();
+    let mut runtime = Runtime::new(move || {
+        let checked = *app_checked.borrow();
+        trs! { checkbox { checked: checked, "Sync" } }
+    });
+    assert_eq!(render(&mut runtime, 10, 1)[0].trim_end(), "[ ] Sync");
+
+    *checked.borrow_mut() = true;
+    assert_eq!(render(&mut runtime, 10, 1)[0].trim_end(), "[x] Sync");
+}
+
+#[test]
+fn test_radio_group() {
+    let picked = Rc::new(RefCell::new(None));
+    let on_change = picked.clone();
+    let mut runtime = Runtime::new(move || {
+        let colors: Vec<Element> = ["red", "green", "blue"]
+            .iter()
+            .map(|color| {
+                let on_change = on_change.clone();
+                trs! {
+                    radio {
+                        group: "color",
+                        checked: *color == "green",
+                        onchange: move |e| *on_change.borrow_mut() = e.value().cloned(),
+                        { *color }
+                    }
+                }
+            })
+            .collect();
+        trs! { block { { colors } } }
+    });
+    let screen = render(&mut runtime, 12, 3);
+    assert_eq!(screen[0].trim_end(), "( ) red");
+    assert_eq!(screen[1].trim_end(), "(•) green");
+
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    assert_eq!(*picked.borrow(), Some(AttrValue::Text("red".to_string())));
+    let screen = render(&mut runtime, 12, 3);
+    assert_eq!(screen[0].trim_end(), "(•) red");
+    assert_eq!(screen[1].trim_end(), "( ) green");
+}
+
+#[test]
+fn test_radio_groups_scoped_to_parent() {
+    let mut runtime = Runtime::new(|| {
+        let sizes = |checked: &'static str| -> Vec<Element> {
+            ["S", "L"]
+                .iter()
+                .map(|size| trs! { radio { group: "size", checked: *size == checked, { *size } } })
+                .collect()
+        };
+        trs! {
+            block {
+                block { { sizes("S") } }
+                block { { sizes("L") } }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 10, 4);
+    assert_eq!(screen[0].trim_end(), "(•) S");
+    assert_eq!(screen[3].trim_end(), "(•) L");
+
+    // Picking in the first block leaves the second one alone.
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    let screen = render(&mut runtime, 10, 4);
+    assert_eq!(screen[1].trim_end(), "(•) L");
+    assert_eq!(screen[3].trim_end(), "(•) L");
+}
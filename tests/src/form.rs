@@ -0,0 +1,252 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{
+    on_submit, ratatui::crossterm::event::KeyCode, trs, AttrValue, Element, Form, FormData,
+    FormError, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+#[derive(Form, Debug, PartialEq)]
+struct Signup {
+    name: String,
+    age: u8,
+    plan: String,
+    newsletter: bool,
+    color: Option<String>,
+}
+
+fn type_text(runtime: &mut Runtime, text: &str) {
+    for c in text.chars() {
+        runtime.handle_event(&key(KeyCode::Char(c)));
+    }
+}
+
+#[test]
+fn test_submit_typed_values() {
+    let submitted = Rc::new(RefCell::new(None));
+    let on_signup = submitted.clone();
+    let mut runtime = Runtime::new(move || {
+        let on_signup = on_signup.clone();
+        trs! {
+            form {
+                onsubmit: on_submit(move |signup: Signup| *on_signup.borrow_mut() = Some(signup)),
+                input { name: "name", placeholder: "Name" }
+                input { name: "age", value: "30" }
+                dropdown { name: "plan", value: "Pro", item { "Free" } item { "Pro" } }
+                checkbox { name: "newsletter", "Newsletter" }
+                radio { name: "color", group: "color", value: "red", "Red" }
+                radio { name: "color", group: "color", value: "blue", "Blue" }
+                button { submit: true, "Sign up" }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 20, 7);
+    assert_eq!(screen[0].trim_end(), "Name");
+    assert_eq!(screen[1].trim_end(), "30");
+    assert_eq!(screen[2], format!("{:<19}▾", "Pro"));
+
+    type_text(&mut runtime, "Ada");
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Up));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+
+    assert_eq!(
+        *submitted.borrow(),
+        Some(Signup {
+            name: "Ada".to_string(),
+            age: 30,
+            plan: "Free".to_string(),
+            newsletter: true,
+            color: Some("blue".to_string()),
+        })
+    );
+    let screen = render(&mut runtime, 20, 7);
+    assert_eq!(screen[0].trim_end(), "Ada");
+    assert_eq!(screen[2], format!("{:<19}▾", "Free"));
+}
+
+#[test]
+fn test_parse_errors() {
+    let mut data = FormData {
+        values: vec![
+            ("name".to_string(), AttrValue::Text("Ada".to_string())),
+            ("age".to_string(), AttrValue::Text("old".to_string())),
+            ("newsletter".to_string(), AttrValue::Bool(false)),
+        ],
+        ..FormData::default()
+    };
+    assert_eq!(
+        data.parse::<Signup>(),
+        Err(FormError::Invalid("age".to_string()))
+    );
+
+    data.values[1].1 = AttrValue::Float(36.5);
+    assert_eq!(
+        data.parse::<Signup>(),
+        Err(FormError::Invalid("age".to_string()))
+    );
+
+    data.values[1].1 = AttrValue::Float(36.0);
+    assert_eq!(
+        data.parse::<Signup>(),
+        Err(FormError::Missing("plan".to_string()))
+    );
+
+    data.values
+        .push(("plan".to_string(), AttrValue::Text("Free".to_string())));
+    let signup = data.parse::<Signup>().unwrap();
+    assert_eq!(signup.age, 36);
+    assert_eq!(signup.color, None);
+}
+
+#[test]
+fn test_validation_messages() {
+    let submits = Rc::new(RefCell::new(0));
+    let on_submit = submits.clone();
+    let mut runtime = Runtime::new(move || {
+        let on_submit = on_submit.clone();
+        trs! {
+            form {
+                onsubmit: move |_| *on_submit.borrow_mut() += 1,
+                input { name: "email", required: true, pattern: "[^@ ]+@[^@ ]+\\.[a-z]{2,}" }
+                input { name: "age", min: 18, max: 99 }
+                input {
+                    name: "user",
+                    validate: |value| match value.to_string().as_str() {
+                        "root" => Err("Taken".to_string()),
+                        _ => Ok(()),
+                    }
+                }
+                button { submit: true, "Save" }
+            }
+        }
+    });
+    let lines = |runtime: &mut Runtime| -> Vec<String> {
+        render(runtime, 24, 7)
+            .into_iter()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    };
+    assert_eq!(lines(&mut runtime), ["", "", "", "", "", "", "[ Save ]"]);
+
+    // Errors show once the form was submitted.
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(lines(&mut runtime)[1], "Required");
+
+    type_text(&mut runtime, "ada@example");
+    assert_eq!(lines(&mut runtime)[1], "Invalid format");
+    type_text(&mut runtime, ".com");
+    assert_eq!(lines(&mut runtime)[1], "");
+
+    runtime.handle_event(&key(KeyCode::Tab));
+    type_text(&mut runtime, "12");
+    assert_eq!(lines(&mut runtime)[3], "Must be at least 18");
+    runtime.handle_event(&key(KeyCode::Tab));
+    type_text(&mut runtime, "root");
+    assert_eq!(lines(&mut runtime)[5], "Taken");
+
+    // A rejected submit moves focus to the first invalid field.
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(*submits.borrow(), 0);
+    lines(&mut runtime);
+    runtime.handle_event(&key(KeyCode::Backspace));
+    runtime.handle_event(&key(KeyCode::Backspace));
+    type_text(&mut runtime, "42");
+    assert_eq!(lines(&mut runtime)[2..4], ["42", ""]);
+
+    runtime.handle_event(&key(KeyCode::Tab));
+    for _ in 0..4 {
+        runtime.handle_event(&key(KeyCode::Backspace));
+    }
+    type_text(&mut runtime, "ada");
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(*submits.borrow(), 1);
+    assert_eq!(lines(&mut runtime)[5], "");
+}
+
+#[test]
+fn test_patterns() {
+    let cases = [
+        ("[0-9]{3}-[0-9]{4}", "555-1234", true),
+        ("[0-9]{3}-[0-9]{4}", "555-12345", false),
+        ("\\d+(\\.\\d\\d)?", "12.50", true),
+        ("\\d+(\\.\\d\\d)?", "12.5", false),
+        ("(cat|dog)s?", "dogs", true),
+        ("(cat|dog)s?", "cow", false),
+        ("[A-Z][a-z]*", "Ada", true),
+        ("[A-Z][a-z]*", "ada", false),
+        ("a.c", "abc", true),
+        ("[^ ]+", "no spaces", false),
+        (
+            "(a|aa)*b",
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            false,
+        ),
+        ("[a-z]+", "ab\ncd", false),
+    ];
+    for (pattern, text, valid) in cases {
+        let submitted = Rc::new(RefCell::new(false));
+        let on_submit = submitted.clone();
+        let mut runtime = Runtime::new(move || {
+            let on_submit = on_submit.clone();
+            trs! {
+                form {
+                    onsubmit: move |_| *on_submit.borrow_mut() = true,
+                    input { name: "field", pattern: pattern, value: text }
+                }
+            }
+        });
+        render(&mut runtime, 20, 2);
+        runtime.handle_event(&key(KeyCode::Enter));
+        assert_eq!(*submitted.borrow(), valid, "{} against {}", text, pattern);
+    }
+}
+
+#[test]
+fn test_dirty_and_touched() {
+    let latest: Rc<RefCell<Option<FormData>>> = Rc::default();
+    let on_change = latest.clone();
+    let mut runtime = Runtime::new(move || {
+        let on_change = on_change.clone();
+        trs! {
+            form {
+                onchange: move |e| *on_change.borrow_mut() = e.form().cloned(),
+                input { name: "title", value: "Draft" }
+                checkbox { name: "done", "Done" }
+            }
+        }
+    });
+    render(&mut runtime, 20, 2);
+    assert!(latest.borrow().is_none());
+
+    type_text(&mut runtime, "!");
+    render(&mut runtime, 20, 2);
+    {
+        let data = latest.borrow();
+        let data = data.as_ref().unwrap();
+        assert_eq!(
+            data.get("title"),
+            Some(&AttrValue::Text("Draft!".to_string()))
+        );
+        assert!(data.is_dirty("title"));
+        assert!(!data.is_dirty("done"));
+        assert!(!data.is_touched("title"));
+    }
+
+    runtime.handle_event(&key(KeyCode::Tab));
+    render(&mut runtime, 20, 2);
+    let data = latest.borrow();
+    let data = data.as_ref().unwrap();
+    assert!(data.is_touched("title"));
+    assert!(!data.is_touched("done"));
+    assert!(data.is_valid());
+}
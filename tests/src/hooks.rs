@@ -0,0 +1,171 @@
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+use turse::{
+    component, provide_context, ratatui::crossterm::event::KeyCode, trs, try_use_context,
+    use_context, use_effect, use_memo, use_ref, use_state, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+#[component]
+fn counter(label: &'static str) -> Element {
+    let count = use_state(|| 0);
+    trs! {
+        button {
+            onclick: move |_| count.update(|n| *n += 1),
+            { format!("{} {}", label, count.get()) }
+        }
+    }
+}
+
+#[test]
+fn test_use_state_per_instance() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            block {
+                { counter("a") }
+                { ["b", "c"].map(counter).to_vec() }
+            }
+        }
+    });
+    render(&mut runtime, 20, 3);
+    runtime.handle_event(&key(KeyCode::Enter));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 3);
+    assert_eq!(screen[0].trim_end(), "[ a 1 ]");
+    assert_eq!(screen[1].trim_end(), "[ b 0 ]");
+    assert_eq!(screen[2].trim_end(), "[ c 2 ]");
+}
+
+#[test]
+fn test_use_memo_and_use_ref() {
+    let computed = Rc::new(RefCell::new(Vec::new()));
+    let input = Rc::new(RefCell::new(2));
+    let mut runtime = Runtime::new({
+        let (computed, input) = (computed.clone(), input.clone());
+        move || {
+            let renders = use_ref(|| 0);
+            *renders.borrow_mut() += 1;
+            let value = *input.borrow();
+            let squared = use_memo(value, |n| {
+                computed.borrow_mut().push(*n);
+                n * n
+            });
+            trs! { text { { format!("{} {}", squared, renders.borrow()) } } }
+        }
+    });
+    assert_eq!(render(&mut runtime, 10, 1)[0].trim_end(), "4 1");
+    assert_eq!(render(&mut runtime, 10, 1)[0].trim_end(), "4 2");
+    *input.borrow_mut() = 3;
+    assert_eq!(render(&mut runtime, 10, 1)[0].trim_end(), "9 3");
+    assert_eq!(*computed.borrow(), [2, 3]);
+}
+
+#[test]
+fn test_use_effect_runs_on_change_and_cleans_up() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let deps = Rc::new(RefCell::new(Some(1)));
+    let mut runtime = Runtime::new({
+        let (log, deps) = (log.clone(), deps.clone());
+        move || {
+            if let Some(dep) = *deps.borrow() {
+                let log = log.clone();
+                use_effect(dep, move |dep| {
+                    log.borrow_mut().push(format!("run {}", dep));
+                    let dep = *dep;
+                    move || log.borrow_mut().push(format!("clean {}", dep))
+                });
+            }
+            trs! { text { "app" } }
+        }
+    });
+    render(&mut runtime, 10, 1);
+    render(&mut runtime, 10, 1);
+    *deps.borrow_mut() = Some(2);
+    render(&mut runtime, 10, 1);
+    *deps.borrow_mut() = None;
+    render(&mut runtime, 10, 1);
+    assert_eq!(*log.borrow(), ["run 1", "clean 1", "run 2", "clean 2"]);
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Theme(&'static str);
+
+#[component]
+fn themed() -> Element {
+    let theme = use_context::<Theme>();
+    trs! { text { { theme.0 } } }
+}
+
+#[component]
+fn dark_section() -> Element {
+    provide_context(Theme("dark"));
+    trs! { block { { themed() } } }
+}
+
+#[test]
+fn test_context_reaches_nested_components() {
+    let mut runtime = Runtime::new(|| {
+        provide_context(Theme("light"));
+        trs! {
+            block {
+                { themed() }
+                { dark_section() }
+                { themed() }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 10, 3);
+    assert_eq!(screen[0].trim_end(), "light");
+    assert_eq!(screen[1].trim_end(), "dark");
+    assert_eq!(screen[2].trim_end(), "light");
+}
+
+#[test]
+fn test_missing_context() {
+    let mut runtime = Runtime::new(|| {
+        assert_eq!(try_use_context::<Theme>(), None);
+        trs! { text { "app" } }
+    });
+    render(&mut runtime, 10, 1);
+}
+
+#[test]
+fn test_set_state_wakes_runtime() {
+    let count = Rc::new(RefCell::new(None));
+    let mut runtime = Runtime::new({
+        let count = count.clone();
+        move || {
+            let state = use_state(|| 0);
+            *count.borrow_mut() = Some(state);
+            trs! { text { { state.get().to_string() } } }
+        }
+    });
+    render(&mut runtime, 20, 1);
+    assert!(!runtime.needs_redraw());
+
+    // As from a task or another callback the runtime does not know about.
+    count.borrow().unwrap().set(1);
+    assert!(runtime.needs_redraw());
+    assert_eq!(render(&mut runtime, 20, 1)[0].trim_end(), "1");
+    assert!(!runtime.needs_redraw());
+}
+
+#[test]
+fn test_panicking_render_leaves_no_frame() {
+    let mut runtime = Runtime::new(|| -> Element {
+        use_state(|| 0);
+        panic!("broken component");
+    });
+    let rendered = panic::catch_unwind(AssertUnwindSafe(|| render(&mut runtime, 20, 1)));
+    assert!(rendered.is_err());
+    // Hooks outside a render still refuse to run.
+    assert!(panic::catch_unwind(|| use_state(|| 0)).is_err());
+}
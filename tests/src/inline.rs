@@ -0,0 +1,42 @@
+use turse::{
+    ratatui::crossterm::event::KeyCode, trs, use_state, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+fn steps() -> Element {
+    let steps = use_state(|| 1);
+    let lines: Vec<Element> = (0..steps.get())
+        .map(|step| trs! { text { { format!("Step {}", step + 1) } } })
+        .collect();
+    trs! {
+        block {
+            button {
+                onclick: move |_| steps.update(|n| *n = if *n == 3 { 1 } else { *n + 1 }),
+                "More"
+            }
+            { lines }
+        }
+    }
+}
+
+#[test]
+fn test_content_height_follows_content() {
+    let mut runtime = Runtime::new(steps).inline(10);
+    assert_eq!(runtime.content_height(), 0);
+    render(&mut runtime, 20, 1);
+    assert_eq!(runtime.content_height(), 2);
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    runtime.handle_event(&key(KeyCode::Enter));
+    // Measured even when the area is too small to show all of it.
+    let screen = render(&mut runtime, 20, 2);
+    assert_eq!(runtime.content_height(), 4);
+    assert_eq!(screen[1].trim_end(), "Step 1");
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 4);
+    assert_eq!(runtime.content_height(), 2);
+    assert_eq!(screen[1].trim_end(), "Step 1");
+    assert_eq!(screen[2].trim_end(), "");
+}
@@ -0,0 +1,201 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use turse::{
+    ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers},
+    trs, AttrValue, Element, Keymap, KeymapError, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+fn ctrl(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+fn keymap(log: &Rc<RefCell<Vec<&'static str>>>) -> Keymap {
+    let mut keymap = Keymap::new();
+    for (action, keys, description) in [
+        ("top", "g g", "Go to top"),
+        ("save", "Ctrl-x Ctrl-s", "Save"),
+        ("quit", "q", "Quit"),
+    ] {
+        let log = log.clone();
+        keymap
+            .bind(action, keys, description, move || {
+                log.borrow_mut().push(action)
+            })
+            .unwrap();
+    }
+    let log = log.clone();
+    keymap
+        .bind_in("editor", "clear", "q", "Clear", move || {
+            log.borrow_mut().push("clear")
+        })
+        .unwrap();
+    keymap
+}
+
+fn app() -> Element {
+    trs! {
+        block {
+            button { "outside" }
+            block { id: "editor", button { "inside" } }
+        }
+    }
+}
+
+#[test]
+fn test_keymap_runs_chords() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(app).keymap(keymap(&log));
+    render(&mut runtime, 20, 4);
+
+    assert!(runtime.handle_event(&key(KeyCode::Char('g'))));
+    assert!(log.borrow().is_empty());
+    assert!(runtime.handle_event(&key(KeyCode::Char('g'))));
+    assert!(runtime.handle_event(&ctrl('x')));
+    assert!(runtime.handle_event(&ctrl('s')));
+    // A key that breaks a chord still runs its own binding.
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    assert!(runtime.handle_event(&key(KeyCode::Char('q'))));
+    assert!(!runtime.handle_event(&key(KeyCode::Char('z'))));
+    assert_eq!(*log.borrow(), ["top", "save", "quit"]);
+}
+
+#[test]
+fn test_keymap_chords_ahead_of_focused_input() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let value = Rc::new(RefCell::new(String::new()));
+    let mut runtime = Runtime::new({
+        let value = value.clone();
+        move || {
+            let value = value.clone();
+            trs! {
+                textarea {
+                    onchange: move |e| *value.borrow_mut() = e.value().unwrap().to_string()
+                }
+            }
+        }
+    })
+    .keymap(keymap(&log));
+    render(&mut runtime, 20, 3);
+
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    runtime.handle_event(&key(KeyCode::Char('q')));
+    assert_eq!(*log.borrow(), ["top"]);
+    assert_eq!(*value.borrow(), "q");
+}
+
+#[test]
+fn test_keymap_scoped_bindings_shadow_global() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(app).keymap(keymap(&log));
+    render(&mut runtime, 20, 4);
+
+    runtime.handle_event(&key(KeyCode::Char('q')));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char('q')));
+    assert_eq!(*log.borrow(), ["quit", "clear"]);
+}
+
+#[test]
+fn test_keymap_global_bindings_wait_for_modal() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let open = Rc::new(Cell::new(true));
+    let mut keymap = keymap(&log);
+    let confirmed = log.clone();
+    keymap
+        .bind_in("confirm", "yes", "y", "Confirm", move || {
+            confirmed.borrow_mut().push("yes")
+        })
+        .unwrap();
+    let mut runtime = Runtime::new({
+        let open = open.clone();
+        move || {
+            let is_open = open.get();
+            let open = open.clone();
+            trs! {
+                block {
+                    id: "editor",
+                    button { "behind" }
+                    modal { id: "confirm", open: is_open, ondismiss: move |_| open.set(false),
+                        button { "Ok" }
+                    }
+                }
+            }
+        }
+    })
+    .keymap(keymap);
+    render(&mut runtime, 20, 6);
+
+    runtime.handle_event(&key(KeyCode::Char('q')));
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    runtime.handle_event(&key(KeyCode::Char('y')));
+    assert_eq!(*log.borrow(), ["yes"]);
+
+    runtime.handle_event(&key(KeyCode::Esc));
+    render(&mut runtime, 20, 6);
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    runtime.handle_event(&key(KeyCode::Char('g')));
+    assert_eq!(*log.borrow(), ["yes", "top"]);
+}
+
+#[test]
+fn test_keymap_conflicts_and_overrides() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut keymap = keymap(&log);
+    assert!(matches!(
+        keymap.bind("first", "g", "", || {}),
+        Err(KeymapError::Conflict { .. })
+    ));
+    assert!(matches!(
+        keymap.bind("bad", "Hyper-x", "", || {}),
+        Err(KeymapError::InvalidKeys(_))
+    ));
+    assert!(matches!(
+        keymap.apply_overrides("# comment\ntop \"G\""),
+        Err(KeymapError::Parse { line: 2, .. })
+    ));
+    // `clear` is also bound in a second scope, where its new keys are taken.
+    keymap
+        .bind_in("list", "clear", "c", "Clear", || {})
+        .unwrap();
+    keymap.bind_in("list", "pick", "p", "Pick", || {}).unwrap();
+    assert!(matches!(
+        keymap.apply_overrides("clear = p"),
+        Err(KeymapError::Conflict { .. })
+    ));
+    keymap
+        .apply_overrides("# user keys\ntop = \"Shift-g\"\nsave = Ctrl-s")
+        .unwrap();
+
+    let mut runtime = Runtime::new(app).keymap(keymap);
+    render(&mut runtime, 20, 4);
+    runtime.handle_event(&Event::Key(KeyEvent::new(
+        KeyCode::Char('G'),
+        KeyModifiers::SHIFT,
+    )));
+    runtime.handle_event(&ctrl('s'));
+    assert_eq!(*log.borrow(), ["top", "save"]);
+}
+
+#[test]
+fn test_keymap_help_overlay() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(app).keymap(keymap(&log));
+    render(&mut runtime, 30, 8);
+
+    assert!(runtime.handle_event(&key(KeyCode::Char('?'))));
+    let screen = render(&mut runtime, 30, 8);
+    assert!(screen[1].contains("┌Keys"));
+    assert!(screen[2].contains("g g            Go to top"));
+    assert!(screen[3].contains("Ctrl-x Ctrl-s  Save"));
+    assert!(screen[4].contains("q              Quit"));
+
+    assert!(runtime.handle_event(&key(KeyCode::Esc)));
+    assert!(!render(&mut runtime, 30, 8)[1].contains("Keys"));
+}
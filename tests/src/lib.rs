@@ -1,3 +1,54 @@
+#[cfg(test)]
+mod button;
+#[cfg(test)]
+mod chart;
+#[cfg(test)]
+mod choice;
+#[cfg(test)]
+mod form;
+#[cfg(test)]
+mod hooks;
+#[cfg(test)]
+mod inline;
+#[cfg(test)]
+mod keymap;
+#[cfg(test)]
+mod lifecycle;
+#[cfg(test)]
+mod list;
+#[cfg(test)]
+mod modal;
+#[cfg(test)]
+mod mouse;
+#[cfg(test)]
+mod node_ref;
+#[cfg(test)]
+mod progress;
+#[cfg(test)]
+mod prompt;
+#[cfg(test)]
+mod router;
+#[cfg(test)]
+mod scroll;
+#[cfg(test)]
+mod store;
+#[cfg(test)]
+mod support;
+#[cfg(test)]
+mod table;
+#[cfg(test)]
+mod tabs;
+#[cfg(test)]
+mod task;
+#[cfg(test)]
+mod textarea;
+#[cfg(test)]
+mod toast;
+#[cfg(test)]
+mod tree;
+#[cfg(test)]
+mod virtual_list;
+
 #[cfg(test)]
 mod tests {
     use turse::{trs, AttrValue, Element, Node};
@@ -240,6 +291,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_expr_child_display() {
+        struct Version(u8, u8);
+
+        impl std::fmt::Display for Version {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "v{}.{}", self.0, self.1)
+            }
+        }
+
+        let version = Version(1, 2);
+        let document = trs! {
+            button {
+                { version }
+            }
+        };
+        match document.inner.unwrap() {
+            Node::Element { children, .. } => {
+                assert_eq!(children.len(), 1);
+                match &children[0] {
+                    Node::Body(s) => assert_eq!(s, "v1.2"),
+                    _ => panic!("expected Body node"),
+                }
+            }
+            _ => panic!("expected Element"),
+        }
+    }
+
     #[test]
     fn test_multiple_children() {
         let document = trs! {
@@ -0,0 +1,50 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{trs, AttrValue, Element, Event, Node, Runtime};
+
+use crate::support::render;
+
+fn app(log: Rc<RefCell<Vec<String>>>, shown: Rc<RefCell<bool>>) -> impl Fn() -> Element {
+    move || {
+        let (mount, unmount, resize) = (log.clone(), log.clone(), log.clone());
+        let chart = trs! {
+            block {
+                height: 3,
+                onmount: move |_| mount.borrow_mut().push("mount".to_string()),
+                onunmount: move |_| unmount.borrow_mut().push("unmount".to_string()),
+                onresize: move |e: &Event| {
+                    let (x, y, width, height) = e.rect().unwrap();
+                    resize
+                        .borrow_mut()
+                        .push(format!("resize {} {} {} {}", x, y, width, height));
+                },
+            }
+        };
+        let chart = shown.borrow().then_some(chart);
+        trs! {
+            block {
+                text { "header" }
+                { chart }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let shown = Rc::new(RefCell::new(true));
+    let mut runtime = Runtime::new(app(log.clone(), shown.clone()));
+    render(&mut runtime, 20, 5);
+    render(&mut runtime, 20, 5);
+    assert_eq!(*log.borrow(), ["mount", "resize 0 1 20 3"]);
+
+    render(&mut runtime, 12, 5);
+    assert_eq!(log.borrow().last().unwrap(), "resize 0 1 12 3");
+
+    *shown.borrow_mut() = false;
+    render(&mut runtime, 12, 5);
+    render(&mut runtime, 12, 5);
+    assert_eq!(log.borrow().len(), 4);
+    assert_eq!(log.borrow().last().unwrap(), "unmount");
+}
@@ -0,0 +1,85 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{ratatui::crossterm::event::KeyCode, trs, AttrValue, Element, Event, Node, Runtime};
+
+use crate::support::{key, render};
+
+const FRUITS: [&str; 6] = ["apple", "banana", "blueberry", "cherry", "date", "fig"];
+
+fn fruits(multiple: bool, events: Rc<RefCell<Vec<Event>>>) -> impl Fn() -> Element {
+    move || {
+        let items: Vec<Element> = FRUITS.iter().map(|f| trs! { item { { *f } } }).collect();
+        let selected = events.clone();
+        let activated = events.clone();
+        trs! {
+            list {
+                height: 3,
+                multiple: multiple,
+                onselect: move |e| selected.borrow_mut().push(e.clone()),
+                onactivate: move |e| activated.borrow_mut().push(e.clone()),
+                { items }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_list_single_select_and_activate() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(fruits(false, events.clone()));
+    render(&mut runtime, 16, 3);
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(
+        *events.borrow(),
+        vec![Event::Select(0), Event::Select(1), Event::Activate(1)]
+    );
+
+    runtime.handle_event(&key(KeyCode::End));
+    let screen = render(&mut runtime, 16, 3);
+    assert!(screen[2].starts_with("fig"));
+}
+
+#[test]
+fn test_list_multi_select() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(fruits(true, events.clone()));
+    render(&mut runtime, 16, 3);
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Char(' ')));
+    assert_eq!(events.borrow().last(), Some(&Event::SelectMany(vec![0, 2])));
+
+    let screen = render(&mut runtime, 16, 3);
+    assert!(screen[0].starts_with("[x] apple"));
+    assert!(screen[1].starts_with("[ ] banana"));
+}
+
+#[test]
+fn test_list_type_to_jump() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(fruits(false, events.clone()));
+    render(&mut runtime, 16, 3);
+
+    runtime.handle_event(&key(KeyCode::Char('b')));
+    runtime.handle_event(&key(KeyCode::Char('l')));
+    assert_eq!(events.borrow().last(), Some(&Event::Select(2)));
+
+    runtime.handle_event(&key(KeyCode::Char('f')));
+    assert_eq!(events.borrow().last(), Some(&Event::Select(5)));
+    let screen = render(&mut runtime, 16, 3);
+    assert!(screen[2].starts_with("fig"));
+}
+
+#[test]
+fn test_list_without_room() {
+    let mut runtime = Runtime::new(fruits(false, Rc::default()));
+    render(&mut runtime, 0, 3);
+    runtime.handle_event(&key(KeyCode::Down));
+    render(&mut runtime, 0, 3);
+}
@@ -0,0 +1,107 @@
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+use turse::{
+    ratatui::{crossterm::event::KeyCode, style::Color},
+    show_dialog, trs, AttrValue, DialogResult, Element, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+fn poll(result: &mut DialogResult) -> Poll<Option<usize>> {
+    Pin::new(result).poll(&mut Context::from_waker(Waker::noop()))
+}
+
+fn app(open: Rc<Cell<bool>>, clicked: Rc<Cell<usize>>) -> impl Fn() -> Element {
+    move || {
+        let is_open = open.get();
+        let open = open.clone();
+        let ok = clicked.clone();
+        let cancel = clicked.clone();
+        trs! {
+            block {
+                button { "behind" }
+                modal { title: "Confirm", width: 16, open: is_open,
+                    ondismiss: move |_| open.set(false),
+                    button { onclick: move |_| ok.set(1), "Ok" }
+                    button { onclick: move |_| cancel.set(2), "Cancel" }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_modal_traps_focus_and_dismisses() {
+    let open = Rc::new(Cell::new(false));
+    let clicked = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(app(open.clone(), clicked.clone()));
+    render(&mut runtime, 20, 6);
+    let behind = runtime.focused().map(str::to_string);
+
+    open.set(true);
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[1], "  ┌Confirm───────┐  ");
+    assert_eq!(screen[2], "  │[ Ok ]        │  ");
+    assert_eq!(screen[3], "  │[ Cancel ]    │  ");
+    assert_eq!(screen[4], "  └──────────────┘  ");
+
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(clicked.get(), 2);
+
+    assert!(runtime.handle_event(&key(KeyCode::Esc)));
+    assert!(!open.get());
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[0].trim_end(), "[ behind ]");
+    assert_eq!(screen[2].trim_end(), "");
+    assert_eq!(runtime.focused().map(str::to_string), behind);
+}
+
+#[test]
+fn test_modal_backdrop() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            block {
+                "under"
+                modal { width: 6, height: 3 }
+            }
+        }
+    });
+    let area = turse::ratatui::layout::Rect::new(0, 0, 10, 5);
+    let mut buf = turse::ratatui::buffer::Buffer::empty(area);
+    runtime.render(area, &mut buf);
+    assert_eq!(buf[(0, 0)].symbol(), "u");
+    assert_eq!(buf[(0, 0)].fg, Color::DarkGray);
+}
+
+#[test]
+fn test_show_dialog_resolves_with_choice() {
+    let mut runtime = Runtime::new(|| trs! { "main" });
+    let mut result = show_dialog("Delete", "Really?", &["Yes", "No"]);
+    let screen = render(&mut runtime, 20, 6);
+    assert!(screen.iter().any(|line| line.contains("Really?")));
+    assert_eq!(poll(&mut result), Poll::Pending);
+
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    assert_eq!(poll(&mut result), Poll::Ready(Some(1)));
+    let screen = render(&mut runtime, 20, 6);
+    assert!(!screen.iter().any(|line| line.contains("Really?")));
+
+    let mut result = show_dialog("Delete", "Again?", &["Yes"]);
+    render(&mut runtime, 20, 6);
+    runtime.handle_event(&key(KeyCode::Esc));
+    assert_eq!(poll(&mut result), Poll::Ready(None));
+
+    drop(show_dialog("Delete", "Dropped", &["Yes"]));
+    let screen = render(&mut runtime, 20, 6);
+    assert!(!screen.iter().any(|line| line.contains("Dropped")));
+}
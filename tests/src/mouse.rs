@@ -0,0 +1,122 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{
+    ratatui::{
+        buffer::Buffer,
+        crossterm::event::{MouseButton, MouseEventKind},
+        layout::Rect,
+        style::Modifier,
+    },
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{mouse, render};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+fn logger(log: &Log, name: &'static str) -> impl Fn(&turse::Event) + 'static {
+    let log = log.clone();
+    move |e| {
+        let detail = match (e.position(), e.delta()) {
+            (Some((x, y)), _) => format!(" {},{}", x, y),
+            (_, Some(delta)) => format!(" {}", delta),
+            _ => String::new(),
+        };
+        log.borrow_mut().push(format!("{}{}", name, detail));
+    }
+}
+
+fn click(runtime: &mut Runtime, x: u16, y: u16) {
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), x, y));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), x, y));
+}
+
+#[test]
+fn test_click_goes_to_innermost_handler() {
+    let log: Log = Rc::default();
+    let app = {
+        let log = log.clone();
+        move || {
+            let outer = logger(&log, "outer");
+            let inner = logger(&log, "inner");
+            trs! {
+                block { onclick: outer,
+                    text { onclick: inner, "inner" }
+                    text { "plain" }
+                }
+            }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    render(&mut runtime, 10, 3);
+    click(&mut runtime, 1, 0);
+    click(&mut runtime, 1, 1);
+    click(&mut runtime, 1, 2);
+    assert_eq!(*log.borrow(), ["inner", "outer", "outer"]);
+}
+
+#[test]
+fn test_hover_events_and_style() {
+    let log: Log = Rc::default();
+    let app = {
+        let log = log.clone();
+        move || {
+            let enter = logger(&log, "enter");
+            let leave = logger(&log, "leave");
+            trs! {
+                block {
+                    text { "-" }
+                    text { hover: "reversed", onmouseenter: enter, onmouseleave: leave, "hot" }
+                }
+            }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    let reversed = |runtime: &mut Runtime| {
+        let area = Rect::new(0, 0, 5, 2);
+        let mut buf = Buffer::empty(area);
+        runtime.render(area, &mut buf);
+        buf[(0, 1)].modifier.contains(Modifier::REVERSED)
+    };
+    assert!(!reversed(&mut runtime));
+
+    runtime.handle_event(&mouse(MouseEventKind::Moved, 2, 1));
+    assert!(reversed(&mut runtime));
+
+    assert!(!runtime.handle_event(&mouse(MouseEventKind::Moved, 1, 1)));
+    runtime.handle_event(&mouse(MouseEventKind::Moved, 1, 0));
+    assert!(!reversed(&mut runtime));
+    assert_eq!(*log.borrow(), ["enter 2,0", "leave 1,-1"]);
+}
+
+#[test]
+fn test_wheel_and_drag_events() {
+    let log: Log = Rc::default();
+    let app = {
+        let log = log.clone();
+        move || {
+            let wheel = logger(&log, "wheel");
+            let start = logger(&log, "start");
+            let drag = logger(&log, "drag");
+            let end = logger(&log, "end");
+            trs! {
+                block {
+                    text { "-" }
+                    text { onwheel: wheel, ondragstart: start, ondrag: drag, ondragend: end, "handle" }
+                }
+            }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    render(&mut runtime, 10, 2);
+    runtime.handle_event(&mouse(MouseEventKind::ScrollDown, 0, 1));
+    runtime.handle_event(&mouse(MouseEventKind::ScrollUp, 0, 0));
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 1, 1));
+    runtime.handle_event(&mouse(MouseEventKind::Drag(MouseButton::Left), 4, 1));
+    runtime.handle_event(&mouse(MouseEventKind::Drag(MouseButton::Left), 6, 0));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 6, 0));
+    assert_eq!(
+        *log.borrow(),
+        ["wheel 3", "start 1,0", "drag 4,0", "drag 6,-1", "end 6,-1"]
+    );
+}
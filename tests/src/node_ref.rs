@@ -0,0 +1,66 @@
+use std::rc::Rc;
+
+use turse::{
+    ratatui::{crossterm::event::KeyCode, layout::Rect},
+    trs, AttrValue, Element, Node, NodeRef, Runtime,
+};
+
+use crate::support::{key, render};
+
+struct Refs {
+    error: NodeRef,
+    editor: NodeRef,
+}
+
+fn app(refs: Rc<Refs>) -> impl Fn() -> Element {
+    move || {
+        let lines: Vec<String> = (0..10).map(|i| format!("line {}", i)).collect();
+        let jump = refs.clone();
+        trs! {
+            block {
+                button { onclick: move |_| jump.error.scroll_into_view(), "Jump" }
+                block {
+                    height: 3,
+                    overflow: "scroll",
+                    block { { lines } }
+                    text { ref: &refs.error, "error here" }
+                }
+                textarea { ref: &refs.editor, value: "draft", height: 1 }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_node_ref_rect_and_scroll_into_view() {
+    let refs = Rc::new(Refs {
+        error: NodeRef::new(),
+        editor: NodeRef::new(),
+    });
+    let mut runtime = Runtime::new(app(refs.clone()));
+    assert!(!refs.error.is_mounted());
+    render(&mut runtime, 20, 5);
+    assert!(refs.error.is_mounted());
+    assert_eq!(refs.editor.rect(), Some(Rect::new(0, 4, 20, 1)));
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 5);
+    assert!(screen[3].starts_with("error here"));
+    assert_eq!(refs.error.rect().map(|r| r.y), Some(3));
+}
+
+#[test]
+fn test_node_ref_focus_and_select_all() {
+    let refs = Rc::new(Refs {
+        error: NodeRef::new(),
+        editor: NodeRef::new(),
+    });
+    let mut runtime = Runtime::new(app(refs.clone()));
+    render(&mut runtime, 20, 5);
+    refs.editor.focus();
+    refs.editor.select_all();
+    render(&mut runtime, 20, 5);
+    runtime.handle_event(&key(KeyCode::Char('n')));
+    runtime.handle_event(&key(KeyCode::Char('o')));
+    assert_eq!(render(&mut runtime, 20, 5)[4].trim_end(), "no");
+}
@@ -0,0 +1,50 @@
+use turse::{
+    ratatui::{buffer::Buffer, layout::Rect, style::Color},
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::render;
+
+#[test]
+fn test_progress_bar() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            block {
+                progress { value: 5, max: 10 }
+                progress { value: 1, max: 4, "up" }
+                progress { value: 9, label: false }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 10, 3);
+    assert_eq!(screen[0], "███50%    ");
+    assert_eq!(screen[1], "██▌ up    ");
+    assert_eq!(screen[2], "▉         ");
+}
+
+#[test]
+fn test_progress_indeterminate() {
+    let mut runtime = Runtime::new(|| {
+        trs! { progress { indeterminate: true, "Loading" } }
+    });
+    let screen = render(&mut runtime, 12, 1);
+    assert!(screen[0].ends_with(" Loading   "));
+    assert_ne!(screen[0].chars().next(), Some(' '));
+}
+
+#[test]
+fn test_gauge_threshold_colors() {
+    let app = |value: i64| {
+        move || -> Element {
+            trs! { gauge { value: value, warn: 70, critical: 90, "cpu" } }
+        }
+    };
+    for (value, color) in [(50, Color::Green), (75, Color::Yellow), (95, Color::Red)] {
+        let mut runtime = Runtime::new(app(value));
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buf = Buffer::empty(area);
+        runtime.render(area, &mut buf);
+        assert_eq!(buf[(4, 0)].symbol(), "─");
+        assert_eq!(buf[(4, 0)].fg, color);
+    }
+}
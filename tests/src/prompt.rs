@@ -0,0 +1,85 @@
+use turse::{prompt, ratatui::crossterm::event::KeyCode, Runtime};
+
+use crate::support::{key, render};
+
+fn press(runtime: &mut Runtime, codes: &[KeyCode]) {
+    for code in codes {
+        runtime.handle_event(&key(*code));
+    }
+}
+
+fn lines(runtime: &mut Runtime) -> Vec<String> {
+    render(runtime, 30, 5)
+        .into_iter()
+        .map(|line| line.trim_end().to_string())
+        .collect()
+}
+
+#[test]
+fn test_text() {
+    let mut prompt = prompt::text("Project name");
+    assert_eq!(
+        lines(prompt.runtime()),
+        ["? Project name", "", "Enter to confirm", "", ""]
+    );
+    press(
+        prompt.runtime(),
+        &[KeyCode::Char('d'), KeyCode::Char('b'), KeyCode::Enter],
+    );
+    assert_eq!(prompt.answer(), Some("db".to_string()));
+    assert_eq!(lines(prompt.runtime())[0], "✔ Project name · db");
+    assert_eq!(prompt.runtime().content_height(), 1);
+}
+
+#[test]
+fn test_select() {
+    let mut prompt = prompt::select("Size", [1, 2, 3]);
+    assert_eq!(lines(prompt.runtime())[1], format!("{:<29}▾", 1));
+    press(prompt.runtime(), &[KeyCode::Down, KeyCode::Down]);
+    assert_eq!(prompt.answer(), None);
+    press(prompt.runtime(), &[KeyCode::Enter]);
+    assert_eq!(prompt.answer(), Some(3));
+    assert_eq!(lines(prompt.runtime())[0], "✔ Size · 3");
+}
+
+#[test]
+fn test_multi_select() {
+    let mut prompt = prompt::multi_select("Features", ["tokio", "smol", "serde"]);
+    assert_eq!(
+        lines(prompt.runtime())[1..4],
+        ["[ ] tokio", "[ ] smol", "[ ] serde"]
+    );
+    press(
+        prompt.runtime(),
+        &[
+            KeyCode::Char(' '),
+            KeyCode::Tab,
+            KeyCode::Tab,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ],
+    );
+    assert_eq!(prompt.answer(), Some(vec!["tokio", "serde"]));
+    assert_eq!(lines(prompt.runtime())[0], "✔ Features · tokio, serde");
+}
+
+#[test]
+fn test_confirm() {
+    let mut prompt = prompt::confirm("Overwrite?", false);
+    lines(prompt.runtime());
+    press(prompt.runtime(), &[KeyCode::Char('x'), KeyCode::Enter]);
+    assert_eq!(prompt.answer(), None);
+    assert_eq!(lines(prompt.runtime())[2], "Answer y or n");
+
+    press(
+        prompt.runtime(),
+        &[KeyCode::Backspace, KeyCode::Char('Y'), KeyCode::Enter],
+    );
+    assert_eq!(prompt.answer(), Some(true));
+
+    let mut prompt = prompt::confirm("Overwrite?", false);
+    lines(prompt.runtime());
+    press(prompt.runtime(), &[KeyCode::Enter]);
+    assert_eq!(prompt.answer(), Some(false));
+    assert_eq!(lines(prompt.runtime())[0], "✔ Overwrite? · No");
+}
@@ -0,0 +1,131 @@
+use turse::{
+    component, provide_context, ratatui::crossterm::event::KeyCode, trs, use_context, use_router,
+    use_state, AttrValue, Element, Node, Params, Router, Runtime,
+};
+
+use crate::support::{key, render};
+
+#[component]
+fn home() -> Element {
+    let router = use_router();
+    trs! {
+        button { onclick: move |_| router.push("/users/7"), "Open user" }
+    }
+}
+
+#[component]
+fn user(id: u32) -> Element {
+    let router = use_router();
+    let back = router.clone();
+    let clicks = use_state(|| 0);
+    trs! {
+        block {
+            button {
+                onclick: move |_| clicks.update(|n| *n += 1),
+                { format!("User {} ({})", id, clicks.get()) }
+            }
+            button { onclick: move |_| router.replace(&format!("/users/{}", id + 1)), "Next" }
+            button { onclick: move |_| { back.back(); }, "Back" }
+        }
+    }
+}
+
+fn app() -> Element {
+    let router = use_router();
+    trs! {
+        block {
+            text { { router.path() } }
+            router_outlet {}
+        }
+    }
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/", |_| home())
+        .route("/users/:id", |params: &Params| match params.get("id") {
+            Some(id) => user(id),
+            None => trs! { "Bad user id" },
+        })
+        .fallback(|_| trs! { "Not found" })
+}
+
+#[test]
+fn test_navigation() {
+    let router = router();
+    let mut runtime = Runtime::new(app).router(router.clone());
+    let screen = render(&mut runtime, 20, 4);
+    assert_eq!(screen[0].trim_end(), "/");
+    assert_eq!(screen[1].trim_end(), "[ Open user ]");
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 4);
+    assert_eq!(screen[0].trim_end(), "/users/7");
+    assert_eq!(screen[1].trim_end(), "[ User 7 (0) ]");
+    assert_eq!(router.history(), ["/", "/users/7"]);
+
+    runtime.handle_event(&key(KeyCode::Enter));
+    render(&mut runtime, 20, 4);
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 4);
+    // Another path is another screen, with state of its own.
+    assert_eq!(screen[1].trim_end(), "[ User 8 (0) ]");
+    assert_eq!(router.history(), ["/", "/users/8"]);
+
+    assert!(router.can_go_back());
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Enter));
+    let screen = render(&mut runtime, 20, 4);
+    assert_eq!(screen[1].trim_end(), "[ Open user ]");
+    assert!(!router.can_go_back());
+    assert!(!router.back());
+}
+
+#[test]
+fn test_params_and_fallback() {
+    let router = router().at("/users/abc");
+    let mut runtime = Runtime::new(app).router(router.clone());
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "Bad user id");
+
+    router.push("/settings/display");
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "Not found");
+
+    router.replace("/users/42");
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "[ User 42 (0) ]");
+    assert_eq!(router.history(), ["/users/abc", "/users/42"]);
+}
+
+#[derive(Clone)]
+struct Theme(&'static str);
+
+#[component]
+fn themed() -> Element {
+    provide_context(Theme("dark"));
+    trs! { block { router_outlet {} } }
+}
+
+#[test]
+fn test_screen_sees_context_around_outlet() {
+    let router = Router::new().route("/", |_| {
+        let theme: Theme = use_context();
+        trs! { text { { format!("theme {}", theme.0) } } }
+    });
+    let mut runtime = Runtime::new(themed).router(router);
+    assert_eq!(render(&mut runtime, 20, 1)[0].trim_end(), "theme dark");
+}
+
+#[test]
+fn test_navigation_wakes_runtime() {
+    let router = router();
+    let mut runtime = Runtime::new(app).router(router.clone());
+    render(&mut runtime, 20, 2);
+    assert!(!runtime.needs_redraw());
+
+    // As from a task that finished loading something.
+    router.push("/users/3");
+    assert!(runtime.needs_redraw());
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "[ User 3 (0) ]");
+    router.back();
+    assert!(runtime.needs_redraw());
+}
@@ -0,0 +1,120 @@
+use turse::{
+    ratatui::crossterm::event::{KeyCode, MouseEventKind},
+    scroll_into_view, scroll_to, trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, mouse, render};
+
+fn log() -> Element {
+    let lines: Vec<String> = (0..20).map(|i| format!("line {}", i)).collect();
+    trs! {
+        block {
+            id: "log",
+            height: 4,
+            overflow: "scroll",
+            { lines }
+        }
+    }
+}
+
+#[test]
+fn test_scroll_renders_window_and_scrollbar() {
+    let mut runtime = Runtime::new(log);
+    let screen = render(&mut runtime, 12, 4);
+    assert!(screen[0].starts_with("line 0"));
+    assert!(screen[3].starts_with("line 3"));
+    assert_ne!(screen[0].chars().last(), Some(' '));
+}
+
+#[test]
+fn test_scroll_keyboard_and_offset_preserved() {
+    let mut runtime = Runtime::new(log);
+    render(&mut runtime, 12, 4);
+    assert_eq!(runtime.focused(), Some("#log"));
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::PageDown));
+    assert!(render(&mut runtime, 12, 4)[0].starts_with("line 4"));
+    assert!(render(&mut runtime, 12, 4)[0].starts_with("line 4"));
+
+    runtime.handle_event(&key(KeyCode::End));
+    let screen = render(&mut runtime, 12, 4);
+    assert!(screen[3].starts_with("line 19"));
+}
+
+#[test]
+fn test_scroll_mouse_wheel() {
+    let mut runtime = Runtime::new(log);
+    render(&mut runtime, 12, 4);
+    runtime.handle_event(&mouse(MouseEventKind::ScrollDown, 2, 2));
+    assert!(render(&mut runtime, 12, 4)[0].starts_with("line 3"));
+    assert!(!runtime.handle_event(&mouse(MouseEventKind::ScrollDown, 2, 10)));
+}
+
+fn list() -> Element {
+    let items: Vec<Element> = (0..20)
+        .map(|i| trs! { text { id: format!("item-{}", i), { format!("item {}", i) } } })
+        .collect();
+    trs! {
+        block {
+            id: "list",
+            height: 5,
+            overflow: "auto",
+            { items }
+        }
+    }
+}
+
+#[test]
+fn test_scroll_to() {
+    let mut runtime = Runtime::new(list);
+    render(&mut runtime, 10, 5);
+
+    scroll_to("list", 10);
+    assert!(render(&mut runtime, 10, 5)[0].starts_with("item 10"));
+
+    scroll_to("list", 100);
+    assert!(render(&mut runtime, 10, 5)[4].starts_with("item 19"));
+}
+
+#[test]
+fn test_scroll_into_view() {
+    let mut runtime = Runtime::new(list);
+    scroll_into_view("item-12");
+    let screen = render(&mut runtime, 10, 5);
+    assert!(screen[4].starts_with("item 12"));
+
+    scroll_into_view("item-3");
+    let screen = render(&mut runtime, 10, 5);
+    assert!(screen[0].starts_with("item 3"));
+}
+
+#[test]
+fn test_scroll_large_log() {
+    let mut runtime = Runtime::new(|| {
+        let lines: Vec<Element> = (0..100_000)
+            .map(|i| match i {
+                80_000 => trs! { text { id: "marker", "marker" } },
+                i => trs! { text { { format!("line {}", i) } } },
+            })
+            .collect();
+        trs! {
+            block { id: "log", height: 3, overflow: "scroll", { lines } }
+        }
+    });
+    render(&mut runtime, 20, 3);
+    // Well past what a `u16` counts, the content still scrolls to its very end.
+    scroll_to("log", usize::MAX);
+    let screen = render(&mut runtime, 20, 3);
+    assert!(screen[0].starts_with("line 99997 "));
+    assert!(screen[2].starts_with("line 99999 "));
+
+    scroll_into_view("marker");
+    let screen = render(&mut runtime, 20, 3);
+    assert!(screen[0].starts_with("marker "));
+    assert!(screen[1].starts_with("line 80001 "));
+
+    let mut runtime = Runtime::new(|| trs! { text { { "\n".repeat(70_000) } } });
+    render(&mut runtime, 20, 3);
+    assert_eq!(runtime.content_height(), u16::MAX);
+}
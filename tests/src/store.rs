@@ -0,0 +1,76 @@
+use std::thread;
+
+use turse::{component, spawn, trs, use_selector, Element, Node, Runtime, Store};
+
+use crate::support::render;
+
+#[derive(Default)]
+struct Session {
+    connected: bool,
+    selection: usize,
+    log: Vec<String>,
+}
+
+#[component]
+fn status(store: Store<Session>) -> Element {
+    let connected = use_selector(&store, |s| s.connected);
+    trs! { text { { if connected { "online" } else { "offline" } } } }
+}
+
+#[component]
+fn cursor(store: Store<Session>) -> Element {
+    let selection = use_selector(&store, |s| s.selection);
+    trs! { text { { format!("row {}", selection) } } }
+}
+
+fn app(store: Store<Session>) -> impl Fn() -> Element {
+    move || {
+        trs! {
+            block {
+                { status(store.clone()) }
+                { cursor(store.clone()) }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_store_redraws_only_for_selected_slices() {
+    let store = Store::<Session>::default();
+    let mut runtime = Runtime::new(app(store.clone()));
+    let screen = render(&mut runtime, 10, 2);
+    assert_eq!(screen[0].trim_end(), "offline");
+    assert!(!runtime.needs_redraw());
+
+    // Nobody selected the log, so appending to it does not need a new frame.
+    store.update(|s| s.log.push("ping".to_string()));
+    assert!(!runtime.needs_redraw());
+
+    let writer = store.clone();
+    thread::spawn(move || writer.update(|s| s.connected = true))
+        .join()
+        .unwrap();
+    assert!(runtime.needs_redraw());
+    let screen = render(&mut runtime, 10, 2);
+    assert_eq!(screen[0].trim_end(), "online");
+    assert_eq!(screen[1].trim_end(), "row 0");
+    assert!(!runtime.needs_redraw());
+
+    store.update(|s| s.connected = true);
+    assert!(!runtime.needs_redraw());
+}
+
+#[test]
+fn test_store_updates_from_tasks() {
+    let store = Store::<Session>::default();
+    let mut runtime = Runtime::new(app(store.clone()));
+    render(&mut runtime, 10, 2);
+
+    let writer = store.clone();
+    spawn(async move { writer.update(|s| s.selection = 3) });
+    assert!(runtime.needs_redraw());
+    render(&mut runtime, 10, 2);
+    assert!(runtime.needs_redraw());
+    assert_eq!(render(&mut runtime, 10, 2)[1].trim_end(), "row 3");
+    assert_eq!(store.read().selection, 3);
+}
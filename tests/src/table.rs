@@ -0,0 +1,144 @@
+use std::{cell::Cell, rc::Rc};
+
+use turse::{ratatui::crossterm::event::KeyCode, trs, AttrValue, Element, Node, Runtime};
+
+use crate::support::{key, render};
+
+const PROCESSES: [(&str, u32); 4] = [("init", 1), ("sshd", 812), ("bash", 97), ("cron", 430)];
+
+fn processes(selected: Rc<Cell<Option<usize>>>) -> impl Fn() -> Element {
+    move || {
+        let rows: Vec<Element> = PROCESSES
+            .iter()
+            .map(|(name, pid)| trs! { row { { *name } { *pid } } })
+            .collect();
+        let selected = selected.clone();
+        trs! {
+            table {
+                striped: true,
+                onselect: move |e| selected.set(e.index()),
+                column { "NAME" }
+                column { width: 5, "PID" }
+                { rows }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_table_header_and_rows() {
+    let mut runtime = Runtime::new(processes(Rc::default()));
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[0].trim_end(), "NAME PID");
+    assert_eq!(screen[1].trim_end(), "init 1");
+    assert_eq!(screen[4].trim_end(), "cron 430");
+}
+
+#[test]
+fn test_table_sort_by_key() {
+    let mut runtime = Runtime::new(processes(Rc::default()));
+    render(&mut runtime, 20, 6);
+
+    runtime.handle_event(&key(KeyCode::Char('2')));
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[0].trim_end(), "NAME PID ▲");
+    assert_eq!(screen[1].trim_end(), "init 1");
+    assert_eq!(screen[2].trim_end(), "bash 97");
+    assert_eq!(screen[4].trim_end(), "sshd 812");
+
+    runtime.handle_event(&key(KeyCode::Char('2')));
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[1].trim_end(), "sshd 812");
+
+    runtime.handle_event(&key(KeyCode::Char('1')));
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[1].trim_end(), "bash   97");
+}
+
+#[test]
+fn test_table_selection_follows_sort() {
+    let selected = Rc::new(Cell::new(None));
+    let mut runtime = Runtime::new(processes(selected.clone()));
+    render(&mut runtime, 20, 6);
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    assert_eq!(selected.get(), Some(1));
+
+    runtime.handle_event(&key(KeyCode::Char('1')));
+    render(&mut runtime, 20, 6);
+    runtime.handle_event(&key(KeyCode::Up));
+    assert_eq!(selected.get(), Some(0));
+}
+
+#[test]
+fn test_table_horizontal_scroll() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            table {
+                column { width: 8, "COMMAND" }
+                column { "ARGUMENTS" }
+                row { "ls" "--all --long" }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 10, 3);
+    assert_eq!(screen[0], "COMMAND  A");
+
+    runtime.handle_event(&key(KeyCode::Right));
+    let screen = render(&mut runtime, 10, 3);
+    assert_eq!(screen[0], "AND  ARGUM");
+    assert_eq!(screen[1], "     --all");
+}
+
+#[test]
+fn test_table_selection_keyed_by_row() {
+    let fresh = Rc::new(Cell::new(false));
+    let selected = Rc::new(Cell::new(None));
+    let mut runtime = Runtime::new({
+        let (fresh, selected) = (fresh.clone(), selected.clone());
+        move || {
+            let mut names = vec!["sshd", "bash"];
+            if fresh.get() {
+                names.insert(0, "top");
+            }
+            let rows: Vec<Element> = names
+                .into_iter()
+                .map(|name| trs! { row { key: name, { name } } })
+                .collect();
+            let selected = selected.clone();
+            trs! {
+                table {
+                    onselect: move |e| selected.set(e.index()),
+                    column { "NAME" }
+                    { rows }
+                }
+            }
+        }
+    });
+    render(&mut runtime, 10, 5);
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    assert_eq!(selected.get(), Some(1));
+
+    // A row showing up above keeps the selection on `bash`, so Up goes to `sshd`.
+    fresh.set(true);
+    render(&mut runtime, 10, 5);
+    runtime.handle_event(&key(KeyCode::Up));
+    assert_eq!(selected.get(), Some(1));
+}
+
+#[test]
+fn test_table_many_rows() {
+    let mut runtime = Runtime::new(|| {
+        let rows: Vec<Element> = (0..100_000)
+            .map(|i| trs! { row { { format!("row {}", i) } } })
+            .collect();
+        trs! { table { column { "NAME" } { rows } } }
+    });
+    render(&mut runtime, 12, 4);
+    runtime.handle_event(&key(KeyCode::End));
+    let screen = render(&mut runtime, 12, 4);
+    assert!(screen[2].starts_with("row 99998"));
+    assert!(screen[3].starts_with("row 99999"));
+}
@@ -0,0 +1,121 @@
+use std::{cell::Cell, rc::Rc};
+
+use turse::{
+    ratatui::crossterm::event::{KeyCode, MouseButton, MouseEventKind},
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, mouse, render};
+
+fn console(active: Rc<Cell<usize>>) -> impl Fn() -> Element {
+    move || {
+        let active = active.clone();
+        trs! {
+            tabs {
+                onselect: move |e| active.set(e.index().unwrap()),
+                tab { title: "Logs", "log panel" }
+                tab { title: "Edit", textarea { height: 1 } }
+                tab { "third" }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tabs_switch_with_keys() {
+    let active = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(console(active.clone()));
+    let screen = render(&mut runtime, 30, 2);
+    assert_eq!(screen[0].trim_end(), " Logs │ Edit │ Tab 3");
+    assert_eq!(screen[1].trim_end(), "log panel");
+
+    assert!(runtime.handle_event(&key(KeyCode::Right)));
+    assert_eq!(active.get(), 1);
+    assert!(runtime.handle_event(&key(KeyCode::Char('3'))));
+    assert_eq!(active.get(), 2);
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "third");
+
+    assert!(!runtime.handle_event(&key(KeyCode::Right)));
+    assert!(!runtime.handle_event(&key(KeyCode::Char('7'))));
+    assert!(runtime.handle_event(&key(KeyCode::Home)));
+    assert_eq!(active.get(), 0);
+}
+
+#[test]
+fn test_tabs_keep_hidden_panel_state() {
+    let mut runtime = Runtime::new(console(Rc::new(Cell::new(0))));
+    render(&mut runtime, 30, 2);
+    runtime.handle_event(&key(KeyCode::Char('2')));
+    render(&mut runtime, 30, 2);
+
+    // Focus the textarea inside the panel and type into it.
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char('h')));
+    runtime.handle_event(&key(KeyCode::Char('i')));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "hi");
+
+    runtime.handle_event(&key(KeyCode::BackTab));
+    runtime.handle_event(&key(KeyCode::Left));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "log panel");
+    runtime.handle_event(&key(KeyCode::Right));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "hi");
+}
+
+#[test]
+fn test_tabs_active_binding() {
+    let active = Rc::new(Cell::new(1));
+    let app = {
+        let active = active.clone();
+        move || {
+            let current = active.get();
+            trs! {
+                tabs { active: current,
+                    tab { "one" }
+                    tab { "two" }
+                }
+            }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "two");
+    runtime.handle_event(&key(KeyCode::Left));
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "one");
+    active.set(0);
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "one");
+    active.set(1);
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "two");
+}
+
+#[test]
+fn test_tabs_click_header() {
+    let active = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(console(active.clone()));
+    render(&mut runtime, 30, 2);
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 9, 0));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 9, 0));
+    assert_eq!(active.get(), 1);
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "");
+}
+
+#[test]
+fn test_tabs_keep_state_of_fields_with_id() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            tabs {
+                tab { title: "Profile", block { input { id: "name" } } }
+                tab { title: "About", "about" }
+            }
+        }
+    });
+    render(&mut runtime, 30, 2);
+    runtime.handle_event(&key(KeyCode::Tab));
+    runtime.handle_event(&key(KeyCode::Char('a')));
+    runtime.handle_event(&key(KeyCode::Char('l')));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "al");
+
+    runtime.handle_event(&key(KeyCode::BackTab));
+    runtime.handle_event(&key(KeyCode::Right));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "about");
+    runtime.handle_event(&key(KeyCode::Left));
+    assert_eq!(render(&mut runtime, 30, 2)[1].trim_end(), "al");
+}
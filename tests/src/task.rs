@@ -0,0 +1,259 @@
+use std::{
+    cell::Cell,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    rc::Rc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use turse::{
+    component, ratatui::crossterm::event::KeyCode, sleep, spawn, spawn_blocking, trs, use_future,
+    use_resource, use_state, AttrValue, Element, Node, ResourceState, Runtime,
+};
+
+use crate::support::{key, render};
+
+// Answers every request with the given body, like the APIs our dashboards poll.
+fn mock_server(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request);
+            thread::sleep(Duration::from_millis(20));
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    address
+}
+
+fn get(address: &str) -> Result<String, String> {
+    let mut stream = TcpStream::connect(address).map_err(|e| e.to_string())?;
+    stream
+        .write_all(b"GET /status HTTP/1.1\r\n\r\n")
+        .map_err(|e| e.to_string())?;
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| e.to_string())?;
+    Ok(response
+        .split("\r\n\r\n")
+        .nth(1)
+        .unwrap_or_default()
+        .to_string())
+}
+
+fn render_until(runtime: &mut Runtime, expected: &str) -> Vec<String> {
+    let start = Instant::now();
+    loop {
+        let screen = render(runtime, 20, 2);
+        if screen[0].trim_end() == expected || start.elapsed() > Duration::from_secs(5) {
+            return screen;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_use_resource_fetches_without_blocking() {
+    let address = mock_server("up");
+    let mut runtime = Runtime::new(move || {
+        let address = address.clone();
+        let status = use_resource(move || {
+            let address = address.clone();
+            spawn_blocking(move || get(&address))
+        });
+        let label = match status.state() {
+            ResourceState::Loading => "loading".to_string(),
+            ResourceState::Ready(body) => body,
+            ResourceState::Error(err) => err,
+        };
+        trs! {
+            block {
+                text { { label } }
+                textarea { height: 1 }
+            }
+        }
+    });
+    assert_eq!(render(&mut runtime, 20, 2)[0].trim_end(), "loading");
+
+    // Input keeps flowing while the request is in flight.
+    runtime.handle_event(&key(KeyCode::Char('x')));
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "x");
+    assert_eq!(render_until(&mut runtime, "up")[0].trim_end(), "up");
+}
+
+struct DropFlag(Rc<Cell<bool>>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.set(true);
+    }
+}
+
+#[test]
+fn test_use_future_cancels_on_unmount() {
+    let shown = Rc::new(Cell::new(true));
+    let dropped = Rc::new(Cell::new(false));
+    let runs = Rc::new(Cell::new(0));
+    let app = {
+        let (shown, dropped, runs) = (shown.clone(), dropped.clone(), runs.clone());
+        move || {
+            if shown.get() {
+                let (dropped, runs) = (dropped.clone(), runs.clone());
+                use_future(move || async move {
+                    let _flag = DropFlag(dropped);
+                    runs.set(runs.get() + 1);
+                    std::future::pending::<()>().await;
+                });
+            }
+            trs! { text { "app" } }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    render(&mut runtime, 20, 2);
+    render(&mut runtime, 20, 2);
+    assert_eq!(runs.get(), 1);
+    assert!(!dropped.get());
+
+    shown.set(false);
+    render(&mut runtime, 20, 2);
+    render(&mut runtime, 20, 2);
+    assert!(dropped.get());
+}
+
+#[component]
+fn saver(saved: Rc<Cell<bool>>) -> Element {
+    let status = use_state(|| "idle");
+    trs! {
+        button {
+            onclick: move |_| {
+                let saved = saved.clone();
+                spawn(async move {
+                    sleep(Duration::from_millis(20)).await;
+                    status.set("saved");
+                    status.update(|status| *status = "done");
+                    saved.set(status.try_get().is_none());
+                });
+            },
+            { status.get() }
+        }
+    }
+}
+
+#[test]
+fn test_set_state_after_unmount() {
+    let shown = Rc::new(Cell::new(true));
+    let saved = Rc::new(Cell::new(false));
+    let mut runtime = Runtime::new({
+        let (shown, saved) = (shown.clone(), saved.clone());
+        move || match shown.get() {
+            true => trs! { block { { saver(saved.clone()) } } },
+            false => trs! { block {} },
+        }
+    });
+    render(&mut runtime, 20, 2);
+    runtime.handle_event(&key(KeyCode::Enter));
+    shown.set(false);
+    render(&mut runtime, 20, 2);
+    let start = Instant::now();
+    while !saved.get() && start.elapsed() < Duration::from_secs(5) {
+        render(&mut runtime, 20, 2);
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert!(saved.get());
+}
+
+#[test]
+fn test_spawn_from_handler() {
+    let count = Rc::new(Cell::new(0));
+    let app = {
+        let count = count.clone();
+        move || {
+            let count = count.clone();
+            trs! {
+                button {
+                    onclick: move |_| {
+                        let count = count.clone();
+                        spawn(async move {
+                            let value = spawn_blocking(|| 41).await;
+                            count.set(value + 1);
+                        });
+                    },
+                    "Go"
+                }
+            }
+        }
+    };
+    let mut runtime = Runtime::new(app);
+    render(&mut runtime, 20, 2);
+    runtime.handle_event(&key(KeyCode::Enter));
+    let start = Instant::now();
+    while count.get() == 0 && start.elapsed() < Duration::from_secs(5) {
+        render(&mut runtime, 20, 2);
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(count.get(), 42);
+}
+
+#[test]
+fn test_sleep_resolves_later() {
+    let done = Rc::new(Cell::new(false));
+    let mut runtime = Runtime::new({
+        let done = done.clone();
+        move || {
+            let done = done.clone();
+            use_future(move || async move {
+                sleep(Duration::from_millis(20)).await;
+                done.set(true);
+            });
+            trs! { text { "app" } }
+        }
+    });
+    let start = Instant::now();
+    render(&mut runtime, 20, 2);
+    assert!(!done.get());
+    while !done.get() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(5));
+        render(&mut runtime, 20, 2);
+    }
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert!(done.get());
+}
+
+#[test]
+fn test_many_sleeps_wake_in_order() {
+    let woken = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new({
+        let woken = woken.clone();
+        move || {
+            let woken = woken.clone();
+            use_future(move || async move {
+                for i in (0..200).rev() {
+                    let woken = woken.clone();
+                    spawn(async move {
+                        sleep(Duration::from_millis(i)).await;
+                        woken.borrow_mut().push(i);
+                    });
+                }
+            });
+            trs! { text { "app" } }
+        }
+    });
+    let start = Instant::now();
+    while woken.borrow().len() < 200 && start.elapsed() < Duration::from_secs(5) {
+        render(&mut runtime, 20, 2);
+        thread::sleep(Duration::from_millis(5));
+    }
+    let woken = woken.borrow();
+    assert_eq!(woken.len(), 200);
+    assert!(woken.windows(2).all(|pair| pair[0] <= pair[1]));
+}
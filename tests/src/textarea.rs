@@ -0,0 +1,119 @@
+use std::{cell::RefCell, rc::Rc};
+
+use turse::{
+    ratatui::crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers},
+    trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, render};
+
+fn ctrl(c: char) -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+}
+
+fn type_text(runtime: &mut Runtime, text: &str) {
+    for c in text.chars() {
+        let code = if c == '\n' {
+            KeyCode::Enter
+        } else {
+            KeyCode::Char(c)
+        };
+        runtime.handle_event(&key(code));
+    }
+}
+
+fn editor(value: Rc<RefCell<String>>) -> impl Fn() -> Element {
+    move || {
+        let current = value.borrow().clone();
+        let value = value.clone();
+        trs! {
+            textarea {
+                height: 3,
+                line_numbers: true,
+                value: current,
+                onchange: move |e| *value.borrow_mut() = e.value().unwrap().to_string()
+            }
+        }
+    }
+}
+
+#[test]
+fn test_textarea_editing_and_binding() {
+    let value = Rc::new(RefCell::new("fix:".to_string()));
+    let mut runtime = Runtime::new(editor(value.clone()));
+    render(&mut runtime, 20, 3);
+
+    type_text(&mut runtime, " typo\n\nbody");
+    runtime.handle_event(&key(KeyCode::Backspace));
+    assert_eq!(*value.borrow(), "fix: typo\n\nbod");
+
+    let screen = render(&mut runtime, 20, 3);
+    assert_eq!(screen[0].trim_end(), "1 fix: typo");
+    assert_eq!(screen[1].trim_end(), "2");
+    assert_eq!(screen[2].trim_end(), "3 bod");
+
+    runtime.handle_event(&key(KeyCode::Up));
+    runtime.handle_event(&key(KeyCode::Up));
+    type_text(&mut runtime, "!");
+    assert_eq!(*value.borrow(), "!fix: typo\n\nbod");
+
+    *value.borrow_mut() = "reset".to_string();
+    assert_eq!(render(&mut runtime, 20, 3)[0].trim_end(), "1 reset");
+}
+
+#[test]
+fn test_textarea_soft_wrap_and_scroll() {
+    let value = Rc::new(RefCell::new(String::new()));
+    let mut runtime = Runtime::new(editor(value.clone()));
+    render(&mut runtime, 8, 3);
+
+    type_text(&mut runtime, "abcdefghijklmnopqrstu");
+    let screen = render(&mut runtime, 8, 3);
+    assert_eq!(screen[0], "  klmno▲");
+    assert_eq!(screen[1], "  pqrst█");
+    assert_eq!(screen[2], "  u    ▼");
+
+    type_text(&mut runtime, "\nx");
+    let screen = render(&mut runtime, 8, 3);
+    assert!(screen[0].starts_with("  pqrst"));
+    assert!(screen[2].starts_with("2 x"));
+
+    for _ in 0..3 {
+        runtime.handle_event(&key(KeyCode::Up));
+    }
+    let screen = render(&mut runtime, 8, 3);
+    assert!(screen[0].starts_with("  klmno"));
+}
+
+#[test]
+fn test_textarea_undo_redo() {
+    let value = Rc::new(RefCell::new(String::new()));
+    let mut runtime = Runtime::new(editor(value.clone()));
+    render(&mut runtime, 20, 3);
+
+    type_text(&mut runtime, "hello world");
+    runtime.handle_event(&key(KeyCode::Backspace));
+    assert_eq!(*value.borrow(), "hello worl");
+
+    runtime.handle_event(&ctrl('z'));
+    assert_eq!(*value.borrow(), "hello world");
+    runtime.handle_event(&ctrl('z'));
+    assert_eq!(*value.borrow(), "hello");
+    runtime.handle_event(&ctrl('z'));
+    assert_eq!(*value.borrow(), "");
+    runtime.handle_event(&ctrl('y'));
+    runtime.handle_event(&ctrl('y'));
+    assert_eq!(*value.borrow(), "hello world");
+}
+
+#[test]
+fn test_textarea_narrower_than_line_numbers() {
+    let mut runtime = Runtime::new(editor(Rc::new(RefCell::new("text".to_string()))));
+    assert!(render(&mut runtime, 2, 3)[0].starts_with('1'));
+
+    // Taller than a `u16` can count stays as tall as it can.
+    let long = "\n".repeat(70_000);
+    let mut runtime = Runtime::new(move || trs! { textarea { value: long.clone() } });
+    render(&mut runtime, 20, 3);
+    assert_eq!(runtime.content_height(), u16::MAX);
+}
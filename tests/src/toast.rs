@@ -0,0 +1,43 @@
+use std::{thread, time::Duration};
+
+use turse::{
+    notify,
+    ratatui::{buffer::Buffer, layout::Rect, style::Color},
+    trs, Corner, Element, Level, Node, Runtime,
+};
+
+use crate::support::render;
+
+#[test]
+fn test_toasts_stack_in_corner_and_expire() {
+    let mut runtime = Runtime::new(|| trs! { "app" })
+        .toast_corner(Corner::TopRight)
+        .toast_timeout(Duration::from_millis(50));
+    notify(Level::Info, "saved");
+    notify(Level::Error, "failed");
+
+    let area = Rect::new(0, 0, 20, 7);
+    let mut buf = Buffer::empty(area);
+    runtime.render(area, &mut buf);
+    let screen = render(&mut runtime, 20, 7);
+    assert_eq!(screen[0], "app         ┌Error─┐");
+    assert_eq!(screen[1], "            │failed│");
+    assert_eq!(screen[3], "             ┌Info─┐");
+    assert_eq!(screen[4], "             │saved│");
+    assert_eq!(buf[(12, 0)].fg, Color::Red);
+    assert_eq!(buf[(13, 3)].fg, Color::Blue);
+
+    thread::sleep(Duration::from_millis(60));
+    let screen = render(&mut runtime, 20, 7);
+    assert_eq!(screen[0].trim_end(), "app");
+
+    // Notifications from threads without a runtime are picked up as well, and ask for a frame.
+    assert!(!runtime.needs_redraw());
+    thread::spawn(|| notify(Level::Success, "done"))
+        .join()
+        .unwrap();
+    assert!(runtime.needs_redraw());
+    let screen = render(&mut runtime, 20, 7);
+    assert_eq!(screen[0], "app        ┌Success┐");
+    assert_eq!(screen[1], "           │done   │");
+}
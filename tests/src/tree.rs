@@ -0,0 +1,168 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use turse::{
+    ratatui::crossterm::event::{KeyCode, MouseButton, MouseEventKind},
+    sleep, trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, mouse, render};
+
+fn files(events: Rc<RefCell<Vec<String>>>) -> impl Fn() -> Element {
+    move || {
+        let selected = events.clone();
+        let expanded = events.clone();
+        trs! {
+            tree {
+                onselect: move |e| selected.borrow_mut().push(format!("select {}", e.value().unwrap())),
+                onexpand: move |e| expanded.borrow_mut().push(format!("expand {}", e.value().unwrap())),
+                node { label: "src", expanded: true,
+                    node { "main.rs" }
+                    node { label: "widgets",
+                        node { "list.rs" }
+                    }
+                }
+                node { label: "Cargo.toml", value: "manifest" }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tree_expand_collapse_with_keys() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(files(events.clone()));
+    let screen = render(&mut runtime, 20, 5);
+    assert_eq!(screen[0].trim_end(), "▾ src");
+    assert_eq!(screen[1].trim_end(), "├─   main.rs");
+    assert_eq!(screen[2].trim_end(), "└─ ▸ widgets");
+    assert_eq!(screen[3].trim_end(), "  Cargo.toml");
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Right));
+    let screen = render(&mut runtime, 20, 5);
+    assert_eq!(screen[2].trim_end(), "└─ ▾ widgets");
+    assert_eq!(screen[3].trim_end(), "   └─   list.rs");
+    assert_eq!(screen[4].trim_end(), "  Cargo.toml");
+
+    runtime.handle_event(&key(KeyCode::Right));
+    runtime.handle_event(&key(KeyCode::Left));
+    runtime.handle_event(&key(KeyCode::Left));
+    runtime.handle_event(&key(KeyCode::Up));
+    runtime.handle_event(&key(KeyCode::Left));
+    runtime.handle_event(&key(KeyCode::Left));
+    let screen = render(&mut runtime, 20, 5);
+    assert_eq!(screen[0].trim_end(), "▸ src");
+    assert_eq!(screen[1].trim_end(), "  Cargo.toml");
+
+    runtime.handle_event(&key(KeyCode::End));
+    assert_eq!(
+        *events.borrow(),
+        [
+            "select src",
+            "select main.rs",
+            "select widgets",
+            "expand widgets",
+            "select list.rs",
+            "select widgets",
+            "select main.rs",
+            "select src",
+            "select manifest",
+        ]
+    );
+}
+
+#[test]
+fn test_tree_click_toggles() {
+    let mut runtime = Runtime::new(files(Rc::new(RefCell::new(Vec::new()))));
+    render(&mut runtime, 20, 5);
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 4, 2));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 4, 2));
+    assert_eq!(render(&mut runtime, 20, 5)[3].trim_end(), "   └─   list.rs");
+    runtime.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 0, 0));
+    runtime.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 0, 0));
+    assert_eq!(render(&mut runtime, 20, 5)[1].trim_end(), "  Cargo.toml");
+    // Needs a scrollbar but has no room for one.
+    render(&mut runtime, 0, 1);
+}
+
+#[test]
+fn test_tree_grows_when_expanded() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut runtime = Runtime::new(move || {
+        let footer = trs! { text { "footer" } };
+        trs! { block { { vec![files(events.clone())(), footer] } } }
+    });
+    assert_eq!(render(&mut runtime, 20, 6)[4].trim_end(), "footer");
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Right));
+    let screen = render(&mut runtime, 20, 6);
+    assert_eq!(screen[3].trim_end(), "   └─   list.rs");
+    assert_eq!(screen[4].trim_end(), "  Cargo.toml");
+    assert_eq!(screen[5].trim_end(), "footer");
+}
+
+#[test]
+fn test_tree_lazy_children() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            tree {
+                load: |value: AttrValue| async move {
+                    vec![format!("{}/a", value), format!("{}/b", value)]
+                        .into_iter()
+                        .map(|name| trs! { node { label: name } })
+                        .collect::<Vec<_>>()
+                },
+                node { label: "root", lazy: true }
+            }
+        }
+    });
+    let screen = render(&mut runtime, 20, 3);
+    assert_eq!(screen[0].trim_end(), "▸ root");
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Right));
+    let screen = render(&mut runtime, 20, 3);
+    assert_eq!(screen[0].trim_end(), "▾ root");
+    assert_eq!(screen[1].trim_end(), "├─   root/a");
+    assert_eq!(screen[2].trim_end(), "└─   root/b");
+}
+
+#[test]
+fn test_tree_slow_load_wakes_runtime() {
+    let mut runtime = Runtime::new(|| {
+        trs! {
+            tree {
+                load: |_| async move {
+                    sleep(Duration::from_millis(10)).await;
+                    sleep(Duration::from_millis(10)).await;
+                    vec![trs! { node { label: "late" } }]
+                },
+                node { label: "root", lazy: true }
+            }
+        }
+    });
+    render(&mut runtime, 20, 2);
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Right));
+    assert_eq!(render(&mut runtime, 20, 2)[1].trim_end(), "└─   Loading…");
+
+    // Frames are only drawn when the load asks for them.
+    let start = Instant::now();
+    let mut screen = render(&mut runtime, 20, 2);
+    while screen[1].trim_end() != "└─   late" && start.elapsed() < Duration::from_secs(5) {
+        if runtime.needs_redraw() {
+            screen = render(&mut runtime, 20, 2);
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(screen[1].trim_end(), "└─   late");
+}
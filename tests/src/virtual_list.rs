@@ -0,0 +1,64 @@
+use std::{cell::Cell, rc::Rc};
+
+use turse::{
+    ratatui::crossterm::event::{KeyCode, MouseEventKind},
+    scroll_to, trs, AttrValue, Element, Node, Runtime,
+};
+
+use crate::support::{key, mouse, render};
+
+fn lines(built: Rc<Cell<usize>>, selected: Rc<Cell<Option<usize>>>) -> impl Fn() -> Element {
+    move || {
+        let built = built.clone();
+        let selected = selected.clone();
+        trs! {
+            virtual_list {
+                id: "lines",
+                count: 1_000_000,
+                overscan: 2,
+                render: move |i| {
+                    built.set(built.get() + 1);
+                    format!("line {}", i)
+                },
+                onselect: move |e| selected.set(e.index())
+            }
+        }
+    }
+}
+
+#[test]
+fn test_virtual_list_builds_only_visible_rows() {
+    let built = Rc::new(Cell::new(0));
+    let mut runtime = Runtime::new(lines(built.clone(), Rc::default()));
+    let screen = render(&mut runtime, 20, 10);
+    assert!(screen[0].starts_with("line 0"));
+    assert!(screen[9].starts_with("line 9"));
+    assert!(built.get() <= 10 + 1 + 2);
+
+    built.set(0);
+    scroll_to("lines", 500_000);
+    let screen = render(&mut runtime, 20, 10);
+    assert!(screen[0].starts_with("line 500000"));
+    assert!(built.get() <= 10 + 1 + 2 * 2);
+}
+
+#[test]
+fn test_virtual_list_selection() {
+    let selected = Rc::new(Cell::new(None));
+    let mut runtime = Runtime::new(lines(Rc::default(), selected.clone()));
+    render(&mut runtime, 20, 10);
+
+    runtime.handle_event(&key(KeyCode::Down));
+    runtime.handle_event(&key(KeyCode::Down));
+    assert_eq!(selected.get(), Some(1));
+
+    runtime.handle_event(&key(KeyCode::End));
+    assert_eq!(selected.get(), Some(999_999));
+    let screen = render(&mut runtime, 20, 10);
+    assert!(screen[9].starts_with("line 999999"));
+
+    runtime.handle_event(&mouse(MouseEventKind::ScrollUp, 1, 1));
+    let screen = render(&mut runtime, 20, 10);
+    assert!(screen[9].starts_with("line 999996"));
+    assert_eq!(selected.get(), Some(999_999));
+}
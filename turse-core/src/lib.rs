@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{any::Any, collections::HashMap, fmt::Display, future::Future, pin::Pin, rc::Rc};
 
 pub trait TurseElement {
     const TAG: &'static str;
@@ -36,6 +36,103 @@ impl IntoNode for Node {
     }
 }
 
+impl IntoNode for &String {
+    fn into_inner_node(self) -> Node {
+        Node::Body(self.clone())
+    }
+}
+
+impl IntoNode for Element {
+    fn into_inner_node(self) -> Node {
+        self.inner.unwrap_or_else(|| Vec::<Node>::new().into())
+    }
+}
+
+impl<T> IntoNode for Vec<T>
+where
+    T: IntoNode,
+{
+    fn into_inner_node(self) -> Node {
+        Node::from(self)
+    }
+}
+
+impl<T> IntoNode for Option<T>
+where
+    T: IntoNode,
+{
+    fn into_inner_node(self) -> Node {
+        match self {
+            Some(t) => t.into_inner_node(),
+            None => Vec::<Node>::new().into(),
+        }
+    }
+}
+
+macro_rules! display_into_node {
+    ($($t:ty),*) => {
+        $(
+            impl IntoNode for $t {
+                fn into_inner_node(self) -> Node {
+                    Node::Body(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+display_into_node!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+);
+
+impl Node {
+    pub fn from_expr(value: impl IntoNode) -> Node {
+        value.into_inner_node()
+    }
+}
+
+// Lets `trs!` take any `IntoNode` child expression, and anything else that is `Display` as text.
+// The trait that applies is picked by autoref: `NodeChild` matches `&Child<T>` as is and wins
+// over `DisplayChild`, which needs one more reference.
+#[doc(hidden)]
+pub mod __private {
+    use std::{cell::Cell, fmt::Display};
+
+    use super::{IntoNode, Node};
+
+    pub struct Child<T>(Cell<Option<T>>);
+
+    impl<T> Child<T> {
+        pub fn new(value: T) -> Self {
+            Self(Cell::new(Some(value)))
+        }
+
+        fn take(&self) -> T {
+            self.0.take().expect("child expression used twice")
+        }
+    }
+
+    pub trait NodeChild {
+        fn child_node(&self) -> Node;
+    }
+
+    impl<T: IntoNode> NodeChild for Child<T> {
+        fn child_node(&self) -> Node {
+            self.take().into_inner_node()
+        }
+    }
+
+    pub trait DisplayChild {
+        fn child_node(&self) -> Node;
+    }
+
+    impl<T: Display> DisplayChild for &Child<T> {
+        fn child_node(&self) -> Node {
+            Node::Body(self.take().to_string())
+        }
+    }
+}
+
 impl From<String> for Node {
     fn from(s: String) -> Self {
         Node::Body(s)
@@ -127,6 +224,183 @@ pub enum AttrValue {
     Int(i64),
     Bool(bool),
     Expr(fn() -> Box<dyn Display>),
+    List(Vec<AttrValue>),
+    Handler(Handler),
+    Render(Render),
+    Load(Load),
+    Ref(Binding),
+    Validate(Validator),
+}
+
+#[cfg_attr(debug_assertions, derive(Debug, PartialEq))]
+#[derive(Clone)]
+pub enum Event {
+    Select(usize),
+    SelectMany(Vec<usize>),
+    Activate(usize),
+    Change(AttrValue),
+    Click,
+    Dismiss,
+    Wheel(isize),
+    // Mouse position relative to the top left corner of the element.
+    Mouse {
+        x: i32,
+        y: i32,
+    },
+    Mount,
+    Unmount,
+    // Values of a `form`, on submit or whenever they change.
+    Form(FormData),
+    // Area the element was laid out in, in screen cells.
+    Resize {
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    },
+}
+
+impl Event {
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Event::Select(i) | Event::Activate(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn indices(&self) -> Option<&[usize]> {
+        match self {
+            Event::SelectMany(indices) => Some(indices),
+            _ => None,
+        }
+    }
+
+    pub fn value(&self) -> Option<&AttrValue> {
+        match self {
+            Event::Change(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn delta(&self) -> Option<isize> {
+        match self {
+            Event::Wheel(delta) => Some(*delta),
+            _ => None,
+        }
+    }
+
+    pub fn position(&self) -> Option<(i32, i32)> {
+        match self {
+            Event::Mouse { x, y } => Some((*x, *y)),
+            _ => None,
+        }
+    }
+
+    pub fn form(&self) -> Option<&FormData> {
+        match self {
+            Event::Form(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The laid out area as `(x, y, width, height)`.
+    pub fn rect(&self) -> Option<(u16, u16, u16, u16)> {
+        match self {
+            Event::Resize {
+                x,
+                y,
+                width,
+                height,
+            } => Some((*x, *y, *width, *height)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Handler(Rc<dyn Fn(&Event)>);
+
+impl Handler {
+    pub fn call(&self, event: &Event) {
+        (self.0)(event)
+    }
+}
+
+#[derive(Clone)]
+pub struct Render(Rc<dyn Fn(usize) -> Node>);
+
+impl Render {
+    pub fn call(&self, index: usize) -> Node {
+        (self.0)(index)
+    }
+}
+
+pub type LoadFuture = Pin<Box<dyn Future<Output = Vec<Node>>>>;
+
+#[derive(Clone)]
+pub struct Load(Rc<dyn Fn(&AttrValue) -> LoadFuture>);
+
+impl Load {
+    pub fn call(&self, value: &AttrValue) -> LoadFuture {
+        (self.0)(value)
+    }
+}
+
+// Whatever the runtime binds to a `ref` attribute.
+#[derive(Clone)]
+pub struct Binding(Rc<dyn Any>);
+
+impl Binding {
+    pub fn downcast<T: 'static>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+type ValidateFn = dyn Fn(&AttrValue) -> Result<(), String>;
+
+// Custom check of a form field, returning the message to show when the value is rejected.
+#[derive(Clone)]
+pub struct Validator(Rc<ValidateFn>);
+
+impl Validator {
+    pub fn call(&self, value: &AttrValue) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Handler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Handler")
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Render {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Render")
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Load {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Load")
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Binding")
+    }
+}
+
+#[cfg(debug_assertions)]
+impl std::fmt::Debug for Validator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Validator")
+    }
 }
 
 #[cfg(debug_assertions)]
@@ -137,6 +411,7 @@ impl PartialEq for AttrValue {
             (Self::Float(lv), Self::Float(rv)) => lv == rv,
             (Self::Int(lv), Self::Int(rv)) => lv == rv,
             (Self::Bool(lv), Self::Bool(rv)) => lv == rv,
+            (Self::List(lv), Self::List(rv)) => lv == rv,
             _ => false,
         }
     }
@@ -152,8 +427,123 @@ impl quote::ToTokens for AttrValue {
             AttrValue::Expr(_) => {
                 quote::quote!(AttrValue::Expr(fn() -> Box<dyn Display>)).to_tokens(tokens)
             }
+            // Only literals are quoted; the rest cannot be written out as tokens.
+            AttrValue::List(_)
+            | AttrValue::Handler(_)
+            | AttrValue::Render(_)
+            | AttrValue::Load(_)
+            | AttrValue::Ref(_)
+            | AttrValue::Validate(_) => {
+                quote::quote!(compile_error!("this attribute value is not a literal"))
+                    .to_tokens(tokens)
+            }
+        }
+    }
+}
+
+impl Display for AttrValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttrValue::Text(s) => write!(f, "{}", s),
+            AttrValue::Float(v) => write!(f, "{}", v),
+            AttrValue::Int(v) => write!(f, "{}", v),
+            AttrValue::Bool(v) => write!(f, "{}", v),
+            AttrValue::Expr(e) => write!(f, "{}", e()),
+            AttrValue::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                Ok(())
+            }
+            AttrValue::Handler(_)
+            | AttrValue::Render(_)
+            | AttrValue::Load(_)
+            | AttrValue::Ref(_)
+            | AttrValue::Validate(_) => Ok(()),
+        }
+    }
+}
+
+impl AttrValue {
+    pub fn handler(f: impl Fn(&Event) + 'static) -> Self {
+        AttrValue::Handler(Handler(Rc::new(f)))
+    }
+
+    pub fn render<T: IntoNode>(f: impl Fn(usize) -> T + 'static) -> Self {
+        AttrValue::Render(Render(Rc::new(move |i| f(i).into_inner_node())))
+    }
+
+    pub fn load<F, T>(f: impl Fn(AttrValue) -> F + 'static) -> Self
+    where
+        F: Future<Output = Vec<T>> + 'static,
+        T: IntoNode,
+    {
+        AttrValue::Load(Load(Rc::new(move |value| {
+            let future = f(value.clone());
+            Box::pin(async move {
+                let nodes = future.await;
+                nodes.into_iter().map(IntoNode::into_inner_node).collect()
+            })
+        })))
+    }
+
+    pub fn binding(value: impl Any) -> Self {
+        AttrValue::Ref(Binding(Rc::new(value)))
+    }
+
+    pub fn validator(f: impl Fn(&AttrValue) -> Result<(), String> + 'static) -> Self {
+        AttrValue::Validate(Validator(Rc::new(f)))
+    }
+
+    /// The value as a whole number; floats with a fraction give `None`.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            AttrValue::Int(v) => Some(*v),
+            AttrValue::Float(v) => {
+                let whole = v.fract() == 0.0 && (i64::MIN as f64..i64::MAX as f64).contains(v);
+                whole.then_some(*v as i64)
+            }
+            _ => self.to_string().trim().parse().ok(),
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            AttrValue::Int(v) => Some(*v as f64),
+            AttrValue::Float(v) => Some(*v),
+            _ => self.to_string().trim().parse().ok(),
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AttrValue::Bool(v) => Some(*v),
+            _ => self.to_string().trim().parse().ok(),
+        }
+    }
+
+    pub fn as_list(&self) -> Vec<AttrValue> {
+        match self {
+            AttrValue::List(items) => items.clone(),
+            _ => self
+                .to_string()
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| AttrValue::Text(item.to_string()))
+                .collect(),
         }
     }
+
+    pub fn as_floats(&self) -> Vec<f64> {
+        self.as_list()
+            .iter()
+            .map(|item| item.as_float().unwrap_or(0.0))
+            .collect()
+    }
 }
 
 pub trait IntoAttrValue {
@@ -217,6 +607,18 @@ impl From<u8> for AttrValue {
     }
 }
 
+impl From<usize> for AttrValue {
+    fn from(v: usize) -> Self {
+        AttrValue::Int(v as i64)
+    }
+}
+
+impl From<isize> for AttrValue {
+    fn from(v: isize) -> Self {
+        AttrValue::Int(v as i64)
+    }
+}
+
 impl From<f64> for AttrValue {
     fn from(v: f64) -> Self {
         AttrValue::Float(v)
@@ -235,6 +637,50 @@ impl From<String> for AttrValue {
     }
 }
 
+impl From<&String> for AttrValue {
+    fn from(v: &String) -> Self {
+        AttrValue::Text(v.clone())
+    }
+}
+
+impl<T> From<Vec<T>> for AttrValue
+where
+    AttrValue: From<T>,
+{
+    fn from(v: Vec<T>) -> Self {
+        AttrValue::List(v.into_iter().map(AttrValue::from).collect())
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for AttrValue
+where
+    AttrValue: From<T>,
+{
+    fn from(v: [T; N]) -> Self {
+        AttrValue::List(v.into_iter().map(AttrValue::from).collect())
+    }
+}
+
+impl<T> From<&[T]> for AttrValue
+where
+    T: Clone,
+    AttrValue: From<T>,
+{
+    fn from(v: &[T]) -> Self {
+        AttrValue::List(v.iter().cloned().map(AttrValue::from).collect())
+    }
+}
+
+impl<T> From<&Vec<T>> for AttrValue
+where
+    T: Clone,
+    AttrValue: From<T>,
+{
+    fn from(v: &Vec<T>) -> Self {
+        AttrValue::List(v.iter().cloned().map(AttrValue::from).collect())
+    }
+}
+
 impl From<bool> for AttrValue {
     fn from(v: bool) -> Self {
         AttrValue::Bool(v)
@@ -269,31 +715,431 @@ impl Element {
     }
 }
 
+/// Values of the named fields of a `form`, with their validation and edit state.
+#[cfg_attr(debug_assertions, derive(Debug, PartialEq))]
+#[derive(Clone, Default)]
+pub struct FormData {
+    // By field `name`, in the order the fields were rendered.
+    pub values: Vec<(String, AttrValue)>,
+    pub errors: Vec<(String, String)>,
+    // Fields whose value differs from the one they were first rendered with.
+    pub dirty: Vec<String>,
+    // Fields that lost focus at least once.
+    pub touched: Vec<String>,
+}
+
+impl FormData {
+    pub fn get(&self, name: &str) -> Option<&AttrValue> {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.errors
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, message)| message.as_str())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn is_dirty(&self, name: &str) -> bool {
+        self.dirty.iter().any(|field| field == name)
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.touched.iter().any(|field| field == name)
+    }
+
+    pub fn field<T: FromAttrValue>(&self, name: &str) -> Result<T, FormError> {
+        match self.get(name) {
+            Some(value) => {
+                T::from_attr_value(value).ok_or_else(|| FormError::Invalid(name.to_string()))
+            }
+            None => T::missing().ok_or_else(|| FormError::Missing(name.to_string())),
+        }
+    }
+
+    pub fn parse<T: Form>(&self) -> Result<T, FormError> {
+        T::from_form(self)
+    }
+}
+
+/// A struct built from the values of a form, usually through `#[derive(Form)]`.
+pub trait Form: Sized {
+    fn from_form(data: &FormData) -> Result<Self, FormError>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormError {
+    Missing(String),
+    Invalid(String),
+}
+
+impl Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormError::Missing(name) => write!(f, "missing field `{}`", name),
+            FormError::Invalid(name) => write!(f, "invalid value for field `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for FormError {}
+
+/// Conversion of a form field value into a typed struct field.
+pub trait FromAttrValue: Sized {
+    fn from_attr_value(value: &AttrValue) -> Option<Self>;
+
+    // Value for a field that is not in the form at all.
+    fn missing() -> Option<Self> {
+        None
+    }
+}
+
+impl FromAttrValue for String {
+    fn from_attr_value(value: &AttrValue) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+impl FromAttrValue for bool {
+    fn from_attr_value(value: &AttrValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl FromAttrValue for f64 {
+    fn from_attr_value(value: &AttrValue) -> Option<Self> {
+        value.as_float()
+    }
+}
+
+impl FromAttrValue for f32 {
+    fn from_attr_value(value: &AttrValue) -> Option<Self> {
+        value.as_float().map(|v| v as f32)
+    }
+}
+
+macro_rules! int_from_attr_value {
+    ($($t:ty),*) => {
+        $(
+            impl FromAttrValue for $t {
+                fn from_attr_value(value: &AttrValue) -> Option<Self> {
+                    value.as_int().and_then(|v| v.try_into().ok())
+                }
+            }
+        )*
+    };
+}
+
+int_from_attr_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+// Empty fields are `None` rather than invalid.
+impl<T: FromAttrValue> FromAttrValue for Option<T> {
+    fn from_attr_value(value: &AttrValue) -> Option<Self> {
+        if value.to_string().is_empty() {
+            return Some(None);
+        }
+        T::from_attr_value(value).map(Some)
+    }
+
+    fn missing() -> Option<Self> {
+        Some(None)
+    }
+}
+
+/// Handler for `onsubmit` that receives the form as a typed struct.
+pub fn on_submit<T: Form>(f: impl Fn(T) + 'static) -> impl Fn(&Event) + 'static {
+    move |event| {
+        if let Some(Ok(value)) = event.form().map(FormData::parse) {
+            f(value)
+        }
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[allow(non_upper_case_globals)]
 pub mod elements {
     use super::TurseElement;
 
+    // Accepted by every element.
+    pub const GLOBAL_ATTRIBUTES: &[&str] = &[
+        "id",
+        "hover",
+        "onclick",
+        "onmouseenter",
+        "onmouseleave",
+        "onwheel",
+        "ondragstart",
+        "ondrag",
+        "ondragend",
+        "onmount",
+        "onunmount",
+        "onresize",
+        "ref",
+    ];
+
     pub struct block;
     impl TurseElement for block {
         const TAG: &'static str = "block";
-        const ATTRIBUTES: &'static [&'static str] = &["width"];
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "height", "border", "title", "overflow"];
     }
     pub struct text;
     impl TurseElement for text {
         const TAG: &'static str = "text";
-        const ATTRIBUTES: &'static [&'static str] = &["width"];
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height"];
     }
 
     pub struct input;
     impl TurseElement for input {
         const TAG: &'static str = "input";
-        const ATTRIBUTES: &'static [&'static str] = &["width"];
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "value",
+            "placeholder",
+            "disabled",
+            "onchange",
+            "name",
+            "required",
+            "pattern",
+            "min",
+            "max",
+            "validate",
+        ];
     }
 
     pub struct dropdown;
     impl TurseElement for dropdown {
         const TAG: &'static str = "dropdown";
-        const ATTRIBUTES: &'static [&'static str] = &["width"];
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "value",
+            "placeholder",
+            "disabled",
+            "onchange",
+            "name",
+            "required",
+            "validate",
+        ];
+    }
+
+    pub struct form;
+    impl TurseElement for form {
+        const TAG: &'static str = "form";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width", "height", "border", "title", "overflow", "onsubmit", "onchange",
+        ];
+    }
+
+    pub struct table;
+    impl TurseElement for table {
+        const TAG: &'static str = "table";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height", "striped", "onselect"];
+    }
+
+    pub struct column;
+    impl TurseElement for column {
+        const TAG: &'static str = "column";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "min_width", "max_width", "sortable"];
+    }
+
+    pub struct row;
+    impl TurseElement for row {
+        const TAG: &'static str = "row";
+        const ATTRIBUTES: &'static [&'static str] = &["key"];
+    }
+
+    pub struct list;
+    impl TurseElement for list {
+        const TAG: &'static str = "list";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "height", "multiple", "onselect", "onactivate"];
+    }
+
+    pub struct item;
+    impl TurseElement for item {
+        const TAG: &'static str = "item";
+        const ATTRIBUTES: &'static [&'static str] = &[];
+    }
+
+    pub struct button;
+    impl TurseElement for button {
+        const TAG: &'static str = "button";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "disabled", "submit", "onclick"];
+    }
+
+    pub struct checkbox;
+    impl TurseElement for checkbox {
+        const TAG: &'static str = "checkbox";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width", "checked", "onchange", "name", "required", "validate",
+        ];
+    }
+
+    pub struct radio;
+    impl TurseElement for radio {
+        const TAG: &'static str = "radio";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width", "group", "value", "checked", "onchange", "name", "required", "validate",
+        ];
+    }
+
+    pub struct toggle;
+    impl TurseElement for toggle {
+        const TAG: &'static str = "toggle";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width", "checked", "onchange", "name", "required", "validate",
+        ];
+    }
+
+    pub struct textarea;
+    impl TurseElement for textarea {
+        const TAG: &'static str = "textarea";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "height",
+            "value",
+            "line_numbers",
+            "disabled",
+            "onchange",
+            "name",
+            "required",
+            "pattern",
+            "validate",
+        ];
+    }
+
+    pub struct progress;
+    impl TurseElement for progress {
+        const TAG: &'static str = "progress";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "value", "min", "max", "label", "indeterminate"];
+    }
+
+    pub struct gauge;
+    impl TurseElement for gauge {
+        const TAG: &'static str = "gauge";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "value", "min", "max", "label", "warn", "critical"];
+    }
+
+    pub struct meter;
+    impl TurseElement for meter {
+        const TAG: &'static str = "meter";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "value", "min", "max", "label", "warn", "critical"];
+    }
+
+    pub struct sparkline;
+    impl TurseElement for sparkline {
+        const TAG: &'static str = "sparkline";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height", "data", "max", "color"];
+    }
+
+    pub struct barchart;
+    impl TurseElement for barchart {
+        const TAG: &'static str = "barchart";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "height",
+            "data",
+            "labels",
+            "max",
+            "bar_width",
+            "color",
+        ];
+    }
+
+    pub struct linechart;
+    impl TurseElement for linechart {
+        const TAG: &'static str = "linechart";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height", "x_label", "y_label"];
+    }
+
+    pub struct series;
+    impl TurseElement for series {
+        const TAG: &'static str = "series";
+        const ATTRIBUTES: &'static [&'static str] = &["name", "data", "color"];
+    }
+
+    pub struct tabs;
+    impl TurseElement for tabs {
+        const TAG: &'static str = "tabs";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height", "active", "onselect"];
+    }
+
+    pub struct tab;
+    impl TurseElement for tab {
+        const TAG: &'static str = "tab";
+        const ATTRIBUTES: &'static [&'static str] = &["title"];
+    }
+
+    pub struct modal;
+    impl TurseElement for modal {
+        const TAG: &'static str = "modal";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "height",
+            "title",
+            "align",
+            "open",
+            "backdrop",
+            "ondismiss",
+        ];
+    }
+
+    pub struct overlay;
+    impl TurseElement for overlay {
+        const TAG: &'static str = "overlay";
+        const ATTRIBUTES: &'static [&'static str] =
+            &["width", "height", "title", "border", "align", "open"];
+    }
+
+    pub struct tree;
+    impl TurseElement for tree {
+        const TAG: &'static str = "tree";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "height",
+            "load",
+            "onselect",
+            "onactivate",
+            "onexpand",
+            "oncollapse",
+        ];
+    }
+
+    pub struct node;
+    impl TurseElement for node {
+        const TAG: &'static str = "node";
+        const ATTRIBUTES: &'static [&'static str] = &["label", "value", "expanded", "lazy"];
+    }
+
+    // Where the runtime renders the screen of the current route.
+    pub struct router_outlet;
+    impl TurseElement for router_outlet {
+        const TAG: &'static str = "router_outlet";
+        const ATTRIBUTES: &'static [&'static str] = &["width", "height"];
+    }
+
+    pub struct virtual_list;
+    impl TurseElement for virtual_list {
+        const TAG: &'static str = "virtual_list";
+        const ATTRIBUTES: &'static [&'static str] = &[
+            "width",
+            "height",
+            "count",
+            "render",
+            "row_height",
+            "overscan",
+            "onselect",
+        ];
     }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenTree};
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
@@ -14,6 +15,66 @@ pub fn trs(input: TokenStream) -> TokenStream {
     TokenStream::from(output)
 }
 
+// Wraps the body of a function returning `Element` in `component`, so every call gets its own
+// hook slots. Expects `turse::component` in scope, which also brings this attribute.
+#[proc_macro_attribute]
+pub fn component(_args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = proc_macro2::TokenStream::from(item).into_iter().collect();
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => body.stream(),
+        _ => {
+            return syn::Error::new(Span::call_site(), "#[component] expects a function")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let signature: proc_macro2::TokenStream = tokens.into_iter().collect();
+    TokenStream::from(quote! {
+        #[track_caller]
+        #signature {
+            component(|| { #body })
+        }
+    })
+}
+
+// Builds the struct from the form fields named like its own fields.
+#[proc_macro_derive(Form)]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            syn::Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(name, "#[derive(Form)] needs named fields")
+                    .to_compile_error()
+                    .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "#[derive(Form)] only supports structs")
+                .to_compile_error()
+                .into();
+        }
+    };
+    let fields = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .map(|ident| {
+            let field = ident.to_string();
+            let field = field.strip_prefix("r#").unwrap_or(&field);
+            quote! { #ident: data.field(#field)? }
+        });
+    TokenStream::from(quote! {
+        impl #impl_generics turse::Form for #name #ty_generics #where_clause {
+            fn from_form(data: &turse::FormData) -> Result<Self, turse::FormError> {
+                Ok(Self { #(#fields),* })
+            }
+        }
+    })
+}
+
 struct TrsCall {
     root: Option<TemplateNode>,
 }
@@ -38,7 +99,38 @@ struct TemplateNode {
     expr_children: Vec<proc_macro2::TokenStream>,
 }
 
-const VALID_ELEMENTS: [&str; 4] = ["block", "text", "input", "dropdown"];
+const VALID_ELEMENTS: [&str; 30] = [
+    "block",
+    "text",
+    "input",
+    "dropdown",
+    "virtual_list",
+    "table",
+    "column",
+    "row",
+    "list",
+    "item",
+    "checkbox",
+    "radio",
+    "toggle",
+    "button",
+    "textarea",
+    "progress",
+    "gauge",
+    "meter",
+    "sparkline",
+    "barchart",
+    "linechart",
+    "series",
+    "tabs",
+    "tab",
+    "modal",
+    "overlay",
+    "tree",
+    "node",
+    "form",
+    "router_outlet",
+];
 
 impl Parse for TemplateNode {
     fn parse(input: ParseStream) -> Result<Self> {
@@ -73,6 +165,12 @@ impl Parse for TemplateNode {
                 let attr_value: AttrValueExpr = content.parse()?;
                 attrs.insert(attr_name.to_string(), attr_value);
                 let _ = content.parse::<Token![,]>();
+            } else if content.peek(Token![ref]) && content.peek2(Token![:]) {
+                content.parse::<Token![ref]>()?;
+                content.parse::<Token![:]>()?;
+                let attr_value: AttrValueExpr = content.parse()?;
+                attrs.insert("ref".to_string(), attr_value);
+                let _ = content.parse::<Token![,]>();
             } else if content.peek(token::Brace) {
                 let expr: syn::Expr = content.parse()?;
                 expr_children.push(quote! { #expr });
@@ -94,6 +192,7 @@ impl Parse for TemplateNode {
 enum AttrValueExpr {
     Literal(turse_core::AttrValue),
     Expr(proc_macro2::TokenStream),
+    Value(proc_macro2::TokenStream),
 }
 
 impl Parse for AttrValueExpr {
@@ -120,7 +219,8 @@ impl Parse for AttrValueExpr {
             let expr_tokens = quote! { #expr };
             Ok(AttrValueExpr::Expr(expr_tokens))
         } else {
-            Err(input.error("expected attribute value"))
+            let expr = input.call(syn::Expr::parse_without_eager_brace)?;
+            Ok(AttrValueExpr::Value(quote! { #expr }))
         }
     }
 }
@@ -150,20 +250,32 @@ impl TrsCall {
 }
 
 impl AttrValueExpr {
-    fn render(&self) -> proc_macro2::TokenStream {
+    fn render(&self, name: &str) -> proc_macro2::TokenStream {
         match self {
             AttrValueExpr::Literal(lit) => quote! { #lit },
+            AttrValueExpr::Expr(expr) | AttrValueExpr::Value(expr) if name.starts_with("on") => {
+                quote! { AttrValue::handler(#expr) }
+            }
+            AttrValueExpr::Expr(expr) | AttrValueExpr::Value(expr) if name == "render" => {
+                quote! { AttrValue::render(#expr) }
+            }
+            AttrValueExpr::Expr(expr) | AttrValueExpr::Value(expr) if name == "load" => {
+                quote! { AttrValue::load(#expr) }
+            }
+            AttrValueExpr::Expr(expr) | AttrValueExpr::Value(expr) if name == "validate" => {
+                quote! { AttrValue::validator(#expr) }
+            }
             AttrValueExpr::Expr(expr) => {
                 quote! { AttrValue::Expr(move || Box::new(#expr)) }
             }
+            AttrValueExpr::Value(expr) => quote! { AttrValue::from(#expr) },
         }
     }
 }
 
 impl TemplateNode {
     fn render(&self) -> proc_macro2::TokenStream {
-        if self.text.is_some() {
-            let text = self.text.as_ref().unwrap();
+        if let Some(text) = &self.text {
             return quote! {
                 Node::Body(#text.to_string())
             };
@@ -174,7 +286,17 @@ impl TemplateNode {
         let expr_children: Vec<_> = self
             .expr_children
             .iter()
-            .map(|e| quote! { Node::Body(#e.to_string()) })
+            .map(|e| {
+                quote! {
+                    {
+                        #[allow(unused_braces)]
+                        let child = #e;
+                        #[allow(unused_imports)]
+                        use turse::__private::{DisplayChild as _, NodeChild as _};
+                        (&turse::__private::Child::new(child)).child_node()
+                    }
+                }
+            })
             .collect();
 
         let all_children = [children, expr_children].concat();
@@ -183,7 +305,7 @@ impl TemplateNode {
             std::collections::HashMap::new()
         };
         for (k, v) in &self.attrs {
-            let v = v.render();
+            let v = v.render(k);
             attrs_expr = quote! {
                 {
                     let mut m = #attrs_expr;
@@ -0,0 +1,25 @@
+use std::{cell::RefCell, collections::VecDeque};
+
+use crate::dialog::Dialog;
+
+pub(crate) enum Command {
+    ScrollTo { id: String, offset: usize },
+    // Keys are mount keys, `#id` for elements with an `id`.
+    ScrollIntoView { key: String },
+    Focus { key: String },
+    Call { key: String, method: String },
+    ShowDialog(Dialog),
+    Exit,
+}
+
+thread_local! {
+    static COMMANDS: RefCell<VecDeque<Command>> = const { RefCell::new(VecDeque::new()) };
+}
+
+pub(crate) fn push(command: Command) {
+    COMMANDS.with(|c| c.borrow_mut().push_back(command));
+}
+
+pub(crate) fn drain() -> Vec<Command> {
+    COMMANDS.with(|c| c.borrow_mut().drain(..).collect())
+}
@@ -0,0 +1,136 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+use turse_core::{AttrValue, Node};
+
+use crate::command::{self, Command};
+
+#[derive(Default)]
+struct Shared {
+    // `Some(None)` once dismissed without choosing a button.
+    result: Option<Option<usize>>,
+    waker: Option<Waker>,
+    dropped: bool,
+}
+
+fn settle(shared: &Rc<RefCell<Shared>>, result: Option<usize>) {
+    let mut shared = shared.borrow_mut();
+    if shared.result.is_none() {
+        shared.result = Some(result);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+fn element(tag: &str, attrs: Vec<(&str, AttrValue)>, children: Vec<Node>) -> Node {
+    Node::Element {
+        tag: tag.to_string(),
+        attrs: attrs
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect::<HashMap<_, _>>(),
+        children,
+    }
+}
+
+pub(crate) struct Dialog {
+    pub key: String,
+    title: String,
+    message: String,
+    buttons: Vec<String>,
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl Dialog {
+    pub fn is_settled(&self) -> bool {
+        let shared = self.shared.borrow();
+        shared.result.is_some() || shared.dropped
+    }
+
+    pub fn node(&self) -> Node {
+        let dismiss = self.shared.clone();
+        let mut children = vec![element(
+            "text",
+            Vec::new(),
+            vec![Node::Body(self.message.clone())],
+        )];
+        for (i, label) in self.buttons.iter().enumerate() {
+            let shared = self.shared.clone();
+            children.push(element(
+                "button",
+                vec![(
+                    "onclick",
+                    AttrValue::handler(move |_| settle(&shared, Some(i))),
+                )],
+                vec![Node::Body(label.clone())],
+            ));
+        }
+        element(
+            "modal",
+            vec![
+                ("title", AttrValue::Text(self.title.clone())),
+                (
+                    "ondismiss",
+                    AttrValue::handler(move |_| settle(&dismiss, None)),
+                ),
+            ],
+            children,
+        )
+    }
+}
+
+/// Resolves to the index of the chosen button, or `None` if the dialog was dismissed.
+pub struct DialogResult {
+    shared: Rc<RefCell<Shared>>,
+}
+
+impl Future for DialogResult {
+    type Output = Option<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.borrow_mut();
+        match shared.result {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Dropping the future closes a dialog that is still open.
+impl Drop for DialogResult {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().dropped = true;
+    }
+}
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Opens a modal dialog above the app on the next render.
+pub fn show_dialog(
+    title: impl Into<String>,
+    message: impl Into<String>,
+    buttons: &[&str],
+) -> DialogResult {
+    let id = NEXT_ID.with(|id| id.replace(id.get() + 1));
+    let shared = Rc::new(RefCell::new(Shared::default()));
+    command::push(Command::ShowDialog(Dialog {
+        key: format!("dialog:{}", id),
+        title: title.into(),
+        message: message.into(),
+        buttons: buttons.iter().map(|b| b.to_string()).collect(),
+        shared: shared.clone(),
+    }));
+    DialogResult { shared }
+}
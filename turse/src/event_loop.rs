@@ -0,0 +1,278 @@
+use std::{
+    collections::VecDeque,
+    future::poll_fn,
+    io::{self, stdout},
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::{
+        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+        execute,
+        terminal::disable_raw_mode,
+    },
+    prelude::CrosstermBackend,
+    DefaultTerminal, Terminal, TerminalOptions, Viewport,
+};
+
+use crate::{
+    command::{self, Command},
+    runtime::Runtime,
+};
+
+const TICK: Duration = Duration::from_millis(100);
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Ends `launch` or `run` once the next frame is on screen.
+pub fn exit() {
+    command::push(Command::Exit);
+}
+
+/// Takes over the terminal and runs the app until Ctrl-C or `exit`.
+///
+/// Apps built with `Runtime::inline` draw below the cursor instead and leave their last frame
+/// on screen.
+///
+/// With the `tokio` or `smol` feature the app runs on that executor; from code that already
+/// runs inside one, await `run` instead.
+pub fn launch(app: impl Into<Runtime>) -> io::Result<()> {
+    #[cfg(feature = "tokio")]
+    return tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(run(app));
+    #[cfg(all(feature = "smol", not(feature = "tokio")))]
+    return smol::block_on(run(app));
+    #[cfg(not(any(feature = "tokio", feature = "smol")))]
+    {
+        let runtime = app.into();
+        let mut screen = Screen::open(runtime.inline_height())?;
+        let result = drive(&mut screen, runtime);
+        screen.close()?;
+        result
+    }
+}
+
+/// Runs the app as a future, so it can be awaited inside an executor the program already has.
+pub async fn run(app: impl Into<Runtime>) -> io::Result<()> {
+    let runtime = app.into();
+    let mut screen = Screen::open(runtime.inline_height())?;
+    #[cfg(any(feature = "tokio", feature = "smol"))]
+    let result = crate::task::host(runtime.waker(), drive_async(&mut screen, runtime)).await;
+    #[cfg(not(any(feature = "tokio", feature = "smol")))]
+    let result = drive_async(&mut screen, runtime).await;
+    screen.close()?;
+    result
+}
+
+// Where the app draws: the whole screen, or a few lines below the cursor that follow the height
+// of the content.
+struct Screen {
+    terminal: DefaultTerminal,
+    // The most lines an inline app may take.
+    inline: Option<u16>,
+}
+
+impl Screen {
+    fn open(inline: Option<u16>) -> io::Result<Self> {
+        let terminal = match inline {
+            // Mouse capture stays off, so the wheel still scrolls the rest of the terminal.
+            Some(_) => ratatui::try_init_with_options(TerminalOptions {
+                viewport: Viewport::Inline(1),
+            })?,
+            None => {
+                let terminal = ratatui::try_init()?;
+                execute!(stdout(), EnableMouseCapture)?;
+                terminal
+            }
+        };
+        Ok(Self { terminal, inline })
+    }
+
+    fn draw(&mut self, runtime: &mut Runtime) -> io::Result<()> {
+        self.render(runtime)?;
+        let Some(max) = self.inline else {
+            return Ok(());
+        };
+        let height = runtime
+            .content_height()
+            .clamp(1, max.max(1))
+            .min(self.terminal.size()?.height);
+        if height == self.terminal.get_frame().area().height {
+            return Ok(());
+        }
+        // Inline viewports cannot change height, so a new one replaces the old from its top line.
+        self.terminal.clear()?;
+        self.terminal = Terminal::with_options(
+            CrosstermBackend::new(stdout()),
+            TerminalOptions {
+                viewport: Viewport::Inline(height),
+            },
+        )?;
+        self.render(runtime)
+    }
+
+    fn render(&mut self, runtime: &mut Runtime) -> io::Result<()> {
+        self.terminal.draw(|frame| {
+            let area = frame.area();
+            runtime.render(area, frame.buffer_mut());
+        })?;
+        Ok(())
+    }
+
+    fn close(mut self) -> io::Result<()> {
+        if self.inline.is_none() {
+            execute!(stdout(), DisableMouseCapture)?;
+            ratatui::restore();
+            return Ok(());
+        }
+        // Continue below the last frame, which stays in the scrollback.
+        let area = self.terminal.get_frame().area();
+        self.terminal
+            .set_cursor_position((0, area.bottom().saturating_sub(1)))?;
+        self.terminal.show_cursor()?;
+        disable_raw_mode()?;
+        println!();
+        Ok(())
+    }
+}
+
+fn is_quit(event: &Event) -> bool {
+    matches!(event, Event::Key(key)
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+#[cfg(not(any(feature = "tokio", feature = "smol")))]
+fn drive(screen: &mut Screen, mut runtime: Runtime) -> io::Result<()> {
+    loop {
+        screen.draw(&mut runtime)?;
+        if runtime.is_exited() {
+            return Ok(());
+        }
+
+        let Some(event) = wait(&runtime)? else {
+            continue;
+        };
+        if is_quit(&event) {
+            return Ok(());
+        }
+        runtime.handle_event(&event);
+    }
+}
+
+// Redraw at least every tick so animations such as spinners keep moving, and as soon as a task
+// has made progress.
+#[cfg(not(any(feature = "tokio", feature = "smol")))]
+fn wait(runtime: &Runtime) -> io::Result<Option<Event>> {
+    let deadline = Instant::now() + TICK;
+    while !runtime.needs_redraw() {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            break;
+        }
+        if event::poll(left.min(FRAME))? {
+            return event::read().map(Some);
+        }
+    }
+    Ok(None)
+}
+
+async fn drive_async(screen: &mut Screen, mut runtime: Runtime) -> io::Result<()> {
+    let input = Input::start();
+    loop {
+        screen.draw(&mut runtime)?;
+        if runtime.is_exited() {
+            return Ok(());
+        }
+
+        let Some(event) = poll_fn(|cx| input.poll(&runtime, cx.waker())).await? else {
+            continue;
+        };
+        if is_quit(&event) {
+            return Ok(());
+        }
+        runtime.handle_event(&event);
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    // `None` marks a tick without input.
+    events: VecDeque<Option<Event>>,
+    error: Option<io::Error>,
+    waker: Option<Waker>,
+    stopped: bool,
+}
+
+// Terminal input read on a thread of its own, so waiting for it never blocks the executor.
+struct Input {
+    queue: Arc<Mutex<Queue>>,
+    reader: Option<JoinHandle<()>>,
+}
+
+impl Input {
+    fn start() -> Self {
+        let queue = Arc::new(Mutex::new(Queue::default()));
+        let reader = queue.clone();
+        let reader = thread::spawn(move || {
+            let mut last = Instant::now();
+            loop {
+                let event = match event::poll(FRAME) {
+                    // Once stopped, whatever arrives is left for the next reader of the terminal.
+                    _ if reader.lock().unwrap().stopped => return,
+                    Ok(true) => event::read().map(Some),
+                    Ok(false) if last.elapsed() >= TICK => Ok(None),
+                    Ok(false) => continue,
+                    Err(err) => Err(err),
+                };
+                last = Instant::now();
+                let mut reader = reader.lock().unwrap();
+                let failed = event.is_err();
+                match event {
+                    Ok(event) => reader.events.push_back(event),
+                    Err(err) => reader.error = Some(err),
+                }
+                if let Some(waker) = reader.waker.take() {
+                    waker.wake();
+                }
+                if failed {
+                    return;
+                }
+            }
+        });
+        Input {
+            queue,
+            reader: Some(reader),
+        }
+    }
+
+    fn poll(&self, runtime: &Runtime, waker: &Waker) -> Poll<io::Result<Option<Event>>> {
+        let mut queue = self.queue.lock().unwrap();
+        // Listen before looking, so progress made in between still wakes us.
+        queue.waker = Some(waker.clone());
+        runtime.wake_on_progress(waker);
+        if let Some(err) = queue.error.take() {
+            return Poll::Ready(Err(err));
+        }
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(Ok(event));
+        }
+        if runtime.needs_redraw() {
+            return Poll::Ready(Ok(None));
+        }
+        Poll::Pending
+    }
+}
+
+// Waits for the reader to finish, so no key typed after the app ends goes to it.
+impl Drop for Input {
+    fn drop(&mut self) {
+        self.queue.lock().unwrap().stopped = true;
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
@@ -0,0 +1,480 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    future::Future,
+    panic::Location,
+    rc::Rc,
+    task::Waker,
+};
+
+use futures_util::{future::LocalBoxFuture, FutureExt};
+use generational_box::{AnyStorage, BorrowError, BorrowMutError, GenerationalBox, UnsyncStorage};
+use turse_core::Element;
+
+use crate::{
+    router::Router,
+    task::{spawn, Task},
+};
+
+// A hook is told apart by the component instance it runs in, where it is called from and how
+// often that call site already ran in this render, so hooks inside conditionals and loops keep
+// their own slots.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct HookId {
+    scope: Rc<str>,
+    location: &'static Location<'static>,
+    occurrence: usize,
+}
+
+#[derive(Default)]
+pub(crate) struct Hooks {
+    slots: HashMap<HookId, Box<dyn Any>>,
+}
+
+struct Scope {
+    id: Rc<str>,
+    counts: HashMap<&'static Location<'static>, usize>,
+    contexts: Vec<Rc<dyn Any>>,
+}
+
+impl Scope {
+    fn new(id: Rc<str>) -> Self {
+        Scope {
+            id,
+            counts: HashMap::new(),
+            contexts: Vec::new(),
+        }
+    }
+
+    fn next(&mut self, location: &'static Location<'static>) -> usize {
+        let count = self.counts.entry(location).or_default();
+        *count += 1;
+        *count - 1
+    }
+}
+
+struct Frame {
+    hooks: Hooks,
+    seen: HashSet<HookId>,
+    // Components being rendered, innermost last.
+    scopes: Vec<Scope>,
+    effects: Vec<Box<dyn FnOnce()>>,
+    // Wakes the runtime for another frame.
+    waker: Waker,
+}
+
+thread_local! {
+    static FRAME: RefCell<Option<Frame>> = const { RefCell::new(None) };
+}
+
+fn with_frame<T>(f: impl FnOnce(&mut Frame) -> T) -> T {
+    FRAME.with(|frame| {
+        let mut frame = frame.borrow_mut();
+        f(frame
+            .as_mut()
+            .expect("hooks can only be called while the app renders"))
+    })
+}
+
+// Runs the app with its hooks available; slots no hook asked for this time are dropped, and
+// effects run once the whole app has rendered.
+pub(crate) fn render<T>(hooks: &mut Hooks, waker: Waker, app: impl FnOnce() -> T) -> T {
+    let frame = Frame {
+        hooks: std::mem::take(hooks),
+        seen: HashSet::new(),
+        scopes: vec![Scope::new(Rc::from("root"))],
+        effects: Vec::new(),
+        waker,
+    };
+    let previous = Restore(FRAME.with(|f| f.borrow_mut().replace(frame)));
+    let result = app();
+    let frame = FRAME.with(|f| f.borrow_mut().take());
+    drop(previous);
+    let Frame {
+        hooks: mut rendered,
+        seen,
+        effects,
+        ..
+    } = frame.expect("hook frame");
+    rendered.slots.retain(|id, _| seen.contains(id));
+    *hooks = rendered;
+    for effect in effects {
+        effect();
+    }
+    result
+}
+
+// Puts back the frame that was current before, also when the app panics.
+struct Restore(Option<Frame>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        FRAME.with(|f| *f.borrow_mut() = self.0.take());
+    }
+}
+
+pub(crate) fn current_waker() -> Waker {
+    with_frame(|frame| frame.waker.clone())
+}
+
+/// Renders a component instance, giving the hooks it calls their own slots.
+#[track_caller]
+pub fn component(render: impl FnOnce() -> Element) -> Element {
+    let location = Location::caller();
+    scoped(
+        |parent| {
+            let occurrence = parent.next(location);
+            format!("{}/{}#{}", parent.id, location, occurrence)
+        },
+        render,
+    )
+}
+
+// A component instance told apart by `key` rather than by where it is rendered from, so a
+// different key starts over with fresh hooks.
+pub(crate) fn keyed(key: &str, render: impl FnOnce() -> Element) -> Element {
+    scoped(|parent| format!("{}/{}", parent.id, key), render)
+}
+
+fn scoped(id: impl FnOnce(&mut Scope) -> String, render: impl FnOnce() -> Element) -> Element {
+    with_frame(|frame| {
+        let parent = frame.scopes.last_mut().expect("root scope");
+        let id = id(parent);
+        frame.scopes.push(Scope::new(Rc::from(id)));
+    });
+    let mut element = render();
+    // Outlets are filled in while the context the component provides is still there.
+    if let (Some(router), Some(node)) = (try_use_context::<Router>(), &mut element.inner) {
+        router.expand(node);
+    }
+    with_frame(|frame| frame.scopes.pop());
+    element
+}
+
+#[track_caller]
+fn slot<T: Clone + 'static>(init: impl FnOnce() -> T) -> T {
+    let location = Location::caller();
+    let (id, existing) = with_frame(|frame| {
+        let scope = frame.scopes.last_mut().expect("root scope");
+        let id = HookId {
+            scope: scope.id.clone(),
+            location,
+            occurrence: scope.next(location),
+        };
+        frame.seen.insert(id.clone());
+        let existing = frame
+            .hooks
+            .slots
+            .get(&id)
+            .and_then(|slot| slot.downcast_ref::<T>())
+            .cloned();
+        (id, existing)
+    });
+    if let Some(value) = existing {
+        return value;
+    }
+    // The initializer runs without the frame borrowed, it may well spawn or render.
+    let value = init();
+    with_frame(|frame| frame.hooks.slots.insert(id, Box::new(value.clone())));
+    value
+}
+
+/// Local state of a component, kept across renders.
+pub struct State<T: 'static> {
+    value: GenerationalBox<T>,
+    // Asks the runtime for another frame once the value changed.
+    waker: GenerationalBox<Waker>,
+}
+
+impl<T> Clone for State<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for State<T> {}
+
+// Tasks and handlers may hold on to a state after its component unmounted. Writes to it are
+// dropped, and reads panic unless they go through `try_get`.
+impl<T: 'static> State<T> {
+    /// Panics once the component is unmounted; see `try_get`.
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.try_get()
+            .expect("state read after its component unmounted")
+    }
+
+    /// The value, or `None` once the component is unmounted.
+    pub fn try_get(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        match self.value.try_read() {
+            Ok(value) => Some(value.clone()),
+            Err(BorrowError::Dropped(_)) => None,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut value = match self.value.try_write() {
+            Ok(value) => value,
+            Err(BorrowMutError::Dropped(_)) => return,
+            Err(err) => panic!("{}", err),
+        };
+        f(&mut value);
+        drop(value);
+        self.waker.read().wake_by_ref();
+    }
+
+    /// Panics once the component is unmounted.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        match self.value.try_read() {
+            Ok(value) => f(&value),
+            Err(BorrowError::Dropped(_)) => panic!("state read after its component unmounted"),
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+#[track_caller]
+pub fn use_state<T: 'static>(init: impl FnOnce() -> T) -> State<T> {
+    // The owner frees the value once the component is gone, which also invalidates its handles.
+    let (_owner, state) = slot(|| {
+        let owner = UnsyncStorage::owner();
+        let value = owner.insert(init());
+        let waker = owner.insert(current_waker());
+        (owner, State { value, waker })
+    });
+    state
+}
+
+/// A value that survives re-renders without being part of the component's output.
+#[track_caller]
+pub fn use_ref<T: 'static>(init: impl FnOnce() -> T) -> Rc<RefCell<T>> {
+    slot(|| Rc::new(RefCell::new(init())))
+}
+
+/// Recomputes the value only when `deps` changed since the last render.
+#[track_caller]
+pub fn use_memo<D, T>(deps: D, compute: impl FnOnce(&D) -> T) -> T
+where
+    D: PartialEq + 'static,
+    T: Clone + 'static,
+{
+    let memo = slot(|| Rc::new(RefCell::new(None::<(D, T)>)));
+    let mut memo = memo.borrow_mut();
+    if let Some((previous, value)) = memo.as_ref()
+        && *previous == deps
+    {
+        return value.clone();
+    }
+    let value = compute(&deps);
+    *memo = Some((deps, value.clone()));
+    value
+}
+
+/// What an effect leaves behind: nothing, or a closure that undoes it.
+pub trait Cleanup {
+    fn into_cleanup(self) -> Option<Box<dyn FnOnce()>>;
+}
+
+impl Cleanup for () {
+    fn into_cleanup(self) -> Option<Box<dyn FnOnce()>> {
+        None
+    }
+}
+
+impl<F: FnOnce() + 'static> Cleanup for F {
+    fn into_cleanup(self) -> Option<Box<dyn FnOnce()>> {
+        Some(Box::new(self))
+    }
+}
+
+struct Effect<D> {
+    deps: Option<D>,
+    cleanup: Option<Box<dyn FnOnce()>>,
+}
+
+impl<D> Drop for Effect<D> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+/// Runs `effect` after the render in which `deps` changed, and its cleanup before the next run
+/// or once the component is gone.
+#[track_caller]
+pub fn use_effect<D, C>(deps: D, effect: impl FnOnce(&D) -> C + 'static)
+where
+    D: PartialEq + 'static,
+    C: Cleanup,
+{
+    let slot = slot(|| {
+        Rc::new(RefCell::new(Effect::<D> {
+            deps: None,
+            cleanup: None,
+        }))
+    });
+    if slot.borrow().deps.as_ref() == Some(&deps) {
+        return;
+    }
+    with_frame(|frame| {
+        frame.effects.push(Box::new(move || {
+            let cleanup = slot.borrow_mut().cleanup.take();
+            if let Some(cleanup) = cleanup {
+                cleanup();
+            }
+            let cleanup = effect(&deps).into_cleanup();
+            let mut slot = slot.borrow_mut();
+            slot.deps = Some(deps);
+            slot.cleanup = cleanup;
+        }))
+    });
+}
+
+/// Makes `value` available to `use_context` in the rest of this component and everything it
+/// renders.
+pub fn provide_context<T: 'static>(value: T) {
+    with_frame(|frame| {
+        let scope = frame.scopes.last_mut().expect("root scope");
+        scope.contexts.push(Rc::new(value));
+    });
+}
+
+/// The closest value of type `T` provided by this component or one it is rendered in.
+pub fn try_use_context<T: Clone + 'static>() -> Option<T> {
+    with_frame(|frame| {
+        frame
+            .scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.contexts.iter().rev())
+            .find_map(|context| context.downcast_ref::<T>())
+            .cloned()
+    })
+}
+
+#[track_caller]
+pub fn use_context<T: Clone + 'static>() -> T {
+    try_use_context().unwrap_or_else(|| {
+        panic!(
+            "no context of type `{}` was provided",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+// Cancels the task once the slot holding it is dropped.
+struct Owned(Task);
+
+impl Drop for Owned {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// Spawns a future the first time it is rendered and cancels it once it is no longer rendered.
+#[track_caller]
+pub fn use_future<F: Future<Output = ()> + 'static>(make: impl FnOnce() -> F) -> Task {
+    slot(|| Rc::new(Owned(spawn(make())))).0.clone()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResourceState<T, E> {
+    Loading,
+    Ready(T),
+    Error(E),
+}
+
+struct ResourceInner<T, E> {
+    state: ResourceState<T, E>,
+    task: Option<Task>,
+    make: Rc<dyn Fn() -> LocalBoxFuture<'static, Result<T, E>>>,
+}
+
+impl<T, E> Drop for ResourceInner<T, E> {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.cancel();
+        }
+    }
+}
+
+/// Value of an async computation started by `use_resource`.
+pub struct Resource<T, E> {
+    inner: Rc<RefCell<ResourceInner<T, E>>>,
+}
+
+impl<T, E> Clone for Resource<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: 'static, E: 'static> Resource<T, E> {
+    pub fn state(&self) -> ResourceState<T, E>
+    where
+        T: Clone,
+        E: Clone,
+    {
+        self.inner.borrow().state.clone()
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self.inner.borrow().state, ResourceState::Loading)
+    }
+
+    /// Cancels a fetch in progress and runs the future again.
+    pub fn restart(&self) {
+        let future = (self.inner.borrow().make)();
+        // The task only holds on weakly so cancelling or unmounting frees the resource.
+        let target = Rc::downgrade(&self.inner);
+        let task = spawn(async move {
+            let state = match future.await {
+                Ok(value) => ResourceState::Ready(value),
+                Err(err) => ResourceState::Error(err),
+            };
+            if let Some(target) = target.upgrade() {
+                target.borrow_mut().state = state;
+            }
+        });
+        let mut inner = self.inner.borrow_mut();
+        if let Some(previous) = inner.task.replace(task) {
+            previous.cancel();
+        }
+        inner.state = ResourceState::Loading;
+    }
+}
+
+/// Runs a fallible future and keeps its latest outcome across renders.
+#[track_caller]
+pub fn use_resource<T, E, F>(make: impl Fn() -> F + 'static) -> Resource<T, E>
+where
+    T: 'static,
+    E: 'static,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    slot(|| {
+        let resource = Resource {
+            inner: Rc::new(RefCell::new(ResourceInner {
+                state: ResourceState::Loading,
+                task: None,
+                make: Rc::new(move || make().boxed_local()),
+            })),
+        };
+        resource.restart();
+        resource
+    })
+}
@@ -0,0 +1,352 @@
+use std::{collections::HashMap, fmt, fs, io, path::Path, rc::Rc};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Combo {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Combo {
+    fn from_event(key: KeyEvent) -> Self {
+        // Shifted characters already come through as the character they produce.
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+        Combo {
+            code: key.code,
+            modifiers,
+        }
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = text;
+        // A trailing `-` is the minus key itself, as in `Ctrl--`.
+        while let Some((prefix, key)) = rest.split_once('-').filter(|(_, key)| !key.is_empty()) {
+            modifiers |= match prefix.to_lowercase().as_str() {
+                "ctrl" | "c" => KeyModifiers::CONTROL,
+                "alt" | "m" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                _ => return None,
+            };
+            rest = key;
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name if name.len() > 1 && name.starts_with('f') => {
+                KeyCode::F(name[1..].parse().ok().filter(|n| (1..=24).contains(n))?)
+            }
+            _ => {
+                let mut chars = rest.chars();
+                let c = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                if modifiers.contains(KeyModifiers::SHIFT) {
+                    modifiers -= KeyModifiers::SHIFT;
+                    KeyCode::Char(c.to_ascii_uppercase())
+                } else {
+                    KeyCode::Char(c)
+                }
+            }
+        };
+        Some(Combo { code, modifiers })
+    }
+}
+
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl-"),
+            (KeyModifiers::ALT, "Alt-"),
+            (KeyModifiers::SHIFT, "Shift-"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+fn parse_keys(text: &str) -> Option<Vec<Combo>> {
+    let keys: Option<Vec<Combo>> = text.split_whitespace().map(Combo::parse).collect();
+    keys.filter(|keys| !keys.is_empty())
+}
+
+fn format_keys(keys: &[Combo]) -> String {
+    keys.iter()
+        .map(|key| key.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug)]
+pub enum KeymapError {
+    InvalidKeys(String),
+    // Two actions in the same scope where one sequence equals or starts the other.
+    Conflict {
+        keys: String,
+        action: String,
+        existing: String,
+    },
+    Parse {
+        line: usize,
+        message: String,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::InvalidKeys(keys) => write!(f, "invalid key sequence `{}`", keys),
+            KeymapError::Conflict {
+                keys,
+                action,
+                existing,
+            } => write!(
+                f,
+                "`{}` for `{}` conflicts with `{}`",
+                keys, action, existing
+            ),
+            KeymapError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            KeymapError::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+impl From<io::Error> for KeymapError {
+    fn from(err: io::Error) -> Self {
+        KeymapError::Io(err)
+    }
+}
+
+struct Binding {
+    action: String,
+    keys: Vec<Combo>,
+    // Id of the element focus has to be in, or `None` for the whole app.
+    scope: Option<String>,
+    description: String,
+    handler: Rc<dyn Fn()>,
+}
+
+// How deep the scope of `b` is among `scopes`, or `None` if the binding doesn't apply.
+fn rank(b: &Binding, scopes: &[String], global: bool) -> Option<usize> {
+    match &b.scope {
+        Some(scope) => scopes.iter().position(|s| s == scope),
+        None => global.then_some(scopes.len()),
+    }
+}
+
+pub(crate) enum Step {
+    Run(Rc<dyn Fn()>),
+    Pending,
+    Miss,
+}
+
+/// App level key bindings, including chords such as `g g` or `Ctrl-x Ctrl-s`.
+#[derive(Default)]
+pub struct Keymap {
+    bindings: Vec<Binding>,
+    overrides: HashMap<String, Vec<Combo>>,
+    pending: Vec<Combo>,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The first key of a chord such as `g g` goes to the keymap ahead of the focused element,
+    /// so a focused input or list no longer sees it. Single keys reach the keymap only when the
+    /// focused element leaves them alone.
+    pub fn bind(
+        &mut self,
+        action: &str,
+        keys: &str,
+        description: &str,
+        handler: impl Fn() + 'static,
+    ) -> Result<(), KeymapError> {
+        self.insert(None, action, keys, description, Rc::new(handler))
+    }
+
+    /// Binds keys that only apply while focus is inside the element with the given `id`.
+    pub fn bind_in(
+        &mut self,
+        scope: &str,
+        action: &str,
+        keys: &str,
+        description: &str,
+        handler: impl Fn() + 'static,
+    ) -> Result<(), KeymapError> {
+        let scope = Some(scope.to_string());
+        self.insert(scope, action, keys, description, Rc::new(handler))
+    }
+
+    /// Applies user overrides, one `action = keys` per line with `#` comments.
+    pub fn load_overrides(&mut self, path: impl AsRef<Path>) -> Result<(), KeymapError> {
+        let text = fs::read_to_string(path)?;
+        self.apply_overrides(&text)
+    }
+
+    pub fn apply_overrides(&mut self, text: &str) -> Result<(), KeymapError> {
+        for (i, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let parse_error = |message: &str| KeymapError::Parse {
+                line: i + 1,
+                message: message.to_string(),
+            };
+            let (action, keys) = line
+                .split_once('=')
+                .ok_or_else(|| parse_error("expected `action = keys`"))?;
+            let action = action.trim();
+            let keys = keys.trim().trim_matches('"');
+            let keys = parse_keys(keys).ok_or_else(|| parse_error("invalid key sequence"))?;
+
+            // The action may be bound in several scopes, and must fit in each of them.
+            let scopes: Vec<Option<String>> = self
+                .bindings
+                .iter()
+                .filter(|b| b.action == action)
+                .map(|b| b.scope.clone())
+                .collect();
+            for scope in &scopes {
+                self.check(scope, action, &keys)?;
+            }
+            for binding in self.bindings.iter_mut().filter(|b| b.action == action) {
+                binding.keys = keys.clone();
+            }
+            self.overrides.insert(action.to_string(), keys);
+        }
+        Ok(())
+    }
+
+    /// Every binding that applies with the given scopes active, as `(keys, description)`.
+    pub(crate) fn active(&self, scopes: &[String], global: bool) -> Vec<(String, String)> {
+        self.bindings
+            .iter()
+            .filter(|b| rank(b, scopes, global).is_some())
+            .map(|b| (format_keys(&b.keys), b.description.clone()))
+            .collect()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    pub(crate) fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    // Whether `key` is the first of a longer sequence bound with the given scopes active.
+    pub(crate) fn starts_chord(&self, key: KeyEvent, scopes: &[String], global: bool) -> bool {
+        let combo = Combo::from_event(key);
+        self.bindings
+            .iter()
+            .any(|b| b.keys.len() > 1 && b.keys[0] == combo && rank(b, scopes, global).is_some())
+    }
+
+    // `scopes` are the ids around the focused element, innermost first; bindings of inner scopes
+    // win over outer ones and over global bindings, which only apply when `global` is set.
+    pub(crate) fn feed(&mut self, key: KeyEvent, scopes: &[String], global: bool) -> Step {
+        let mut keys = std::mem::take(&mut self.pending);
+        keys.push(Combo::from_event(key));
+
+        let rank = |b: &Binding| rank(b, scopes, global);
+        let matched = self
+            .bindings
+            .iter()
+            .filter(|b| b.keys == keys)
+            .filter_map(|b| rank(b).map(|rank| (rank, b)))
+            .min_by_key(|(rank, _)| *rank);
+        if let Some((_, binding)) = matched {
+            return Step::Run(binding.handler.clone());
+        }
+        let prefix = self
+            .bindings
+            .iter()
+            .any(|b| rank(b).is_some() && b.keys.len() > keys.len() && b.keys.starts_with(&keys));
+        if prefix {
+            self.pending = keys;
+            Step::Pending
+        } else {
+            Step::Miss
+        }
+    }
+
+    fn insert(
+        &mut self,
+        scope: Option<String>,
+        action: &str,
+        keys: &str,
+        description: &str,
+        handler: Rc<dyn Fn()>,
+    ) -> Result<(), KeymapError> {
+        let keys = match self.overrides.get(action) {
+            Some(keys) => keys.clone(),
+            None => parse_keys(keys).ok_or_else(|| KeymapError::InvalidKeys(keys.to_string()))?,
+        };
+        self.check(&scope, action, &keys)?;
+        self.bindings.push(Binding {
+            action: action.to_string(),
+            keys,
+            scope,
+            description: description.to_string(),
+            handler,
+        });
+        Ok(())
+    }
+
+    fn check(
+        &self,
+        scope: &Option<String>,
+        action: &str,
+        keys: &[Combo],
+    ) -> Result<(), KeymapError> {
+        let conflict = self.bindings.iter().find(|b| {
+            &b.scope == scope
+                && b.action != action
+                && (b.keys.starts_with(keys) || keys.starts_with(&b.keys))
+        });
+        match conflict {
+            Some(existing) => Err(KeymapError::Conflict {
+                keys: format_keys(keys),
+                action: action.to_string(),
+                existing: existing.action.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
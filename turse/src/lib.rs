@@ -1,9 +1,52 @@
+// Lets `trs!` refer to `turse::` from inside this crate too.
+extern crate self as turse;
+
+mod command;
+mod dialog;
+mod event_loop;
+mod hooks;
+mod keymap;
+mod node_ref;
+mod pattern;
+pub mod prompt;
+mod router;
+mod runtime;
+mod scroll;
+mod selection;
+mod state;
+mod store;
+mod task;
+mod toast;
+mod widget;
+mod widgets;
+
+#[doc(hidden)]
+pub use turse_core::__private;
 pub use turse_core::elements;
 pub use turse_core::AttrValue;
 pub use turse_core::Element;
+pub use turse_core::Event;
 pub use turse_core::IntoAttrValue;
 pub use turse_core::IntoNode;
 pub use turse_core::Node;
 pub use turse_core::TurseElement;
+pub use turse_core::{on_submit, Form, FormData, FormError, FromAttrValue};
+
+pub use turse_macro::{component, trs, Form};
+
+pub use ratatui;
 
-pub use turse_macro::trs;
+pub use dialog::{show_dialog, DialogResult};
+pub use event_loop::{exit, launch, run};
+pub use hooks::{
+    component, provide_context, try_use_context, use_context, use_effect, use_future, use_memo,
+    use_ref, use_resource, use_state, Cleanup, Resource, ResourceState, State,
+};
+pub use keymap::{Keymap, KeymapError};
+pub use node_ref::NodeRef;
+pub use router::{use_router, Params, Router};
+pub use runtime::Runtime;
+pub use scroll::{scroll_into_view, scroll_to};
+pub use store::{use_selector, Store};
+pub use task::{sleep, spawn, spawn_blocking, Task};
+pub use toast::{notify, Corner, Level};
@@ -0,0 +1,97 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use ratatui::layout::Rect;
+use turse_core::AttrValue;
+
+use crate::command::{self, Command};
+
+#[derive(Default)]
+struct Target {
+    key: Option<String>,
+    rect: Option<Rect>,
+}
+
+/// Handle to a rendered element, bound through its `ref` attribute.
+#[derive(Clone, Default)]
+pub struct NodeRef {
+    target: Rc<RefCell<Target>>,
+}
+
+impl NodeRef {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_mounted(&self) -> bool {
+        self.target.borrow().key.is_some()
+    }
+
+    /// Where the element was drawn in the last frame, in screen cells.
+    pub fn rect(&self) -> Option<Rect> {
+        self.target.borrow().rect
+    }
+
+    pub fn focus(&self) {
+        if let Some(key) = self.key() {
+            command::push(Command::Focus { key });
+        }
+    }
+
+    /// Scrolls every container around the element until it is visible.
+    pub fn scroll_into_view(&self) {
+        if let Some(key) = self.key() {
+            command::push(Command::ScrollIntoView { key });
+        }
+    }
+
+    /// Selects all text of a `textarea`.
+    pub fn select_all(&self) {
+        self.call("select_all");
+    }
+
+    /// Runs a widget specific method, such as `select_all` or `clear` on a `textarea`.
+    pub fn call(&self, method: &str) {
+        if let Some(key) = self.key() {
+            command::push(Command::Call {
+                key,
+                method: method.to_string(),
+            });
+        }
+    }
+
+    fn key(&self) -> Option<String> {
+        self.target.borrow().key.clone()
+    }
+
+    pub(crate) fn bind(&self, key: &str, rect: Rect) {
+        let mut target = self.target.borrow_mut();
+        target.key = Some(key.to_string());
+        target.rect = Some(rect);
+    }
+
+    pub(crate) fn unbind(&self, key: &str) {
+        let mut target = self.target.borrow_mut();
+        if target.key.as_deref() == Some(key) {
+            *target = Target::default();
+        }
+    }
+}
+
+impl From<&NodeRef> for AttrValue {
+    fn from(node_ref: &NodeRef) -> Self {
+        AttrValue::binding(node_ref.clone())
+    }
+}
+
+impl From<NodeRef> for AttrValue {
+    fn from(node_ref: NodeRef) -> Self {
+        AttrValue::binding(node_ref)
+    }
+}
+
+pub(crate) fn node_ref(attrs: &HashMap<String, AttrValue>) -> Option<&NodeRef> {
+    match attrs.get("ref") {
+        Some(AttrValue::Ref(binding)) => binding.downcast(),
+        _ => None,
+    }
+}
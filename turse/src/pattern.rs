@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
+struct Compiled {
+    // `None` if the pattern does not compile.
+    regex: Option<Regex>,
+    // The last text checked and whether it matched, as fields are validated on every render.
+    last: Option<(String, bool)>,
+}
+
+// The `pattern` attributes of a form, each compiled once and anchored so the whole value has
+// to match.
+#[derive(Default)]
+pub(crate) struct Patterns(HashMap<String, Compiled>);
+
+impl Patterns {
+    // Whether `text` matches `pattern`, or `None` if the pattern is not a valid regex.
+    pub fn is_match(&mut self, pattern: &str, text: &str) -> Option<bool> {
+        let compiled = self
+            .0
+            .entry(pattern.to_string())
+            .or_insert_with(|| Compiled {
+                regex: Regex::new(&format!("^(?:{})$", pattern)).ok(),
+                last: None,
+            });
+        let regex = compiled.regex.as_ref()?;
+        match &compiled.last {
+            Some((last, matched)) if last == text => Some(*matched),
+            _ => {
+                let matched = regex.is_match(text);
+                compiled.last = Some((text.to_string(), matched));
+                Some(matched)
+            }
+        }
+    }
+}
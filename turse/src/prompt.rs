@@ -0,0 +1,239 @@
+use std::{cell::RefCell, fmt::Display, io, rc::Rc};
+
+use turse_core::{AttrValue, Element, Event, FormData, Node};
+use turse_macro::trs;
+
+use crate::{
+    event_loop::{self, exit, launch},
+    hooks::use_state,
+    runtime::Runtime,
+};
+
+// The most lines a prompt takes below the cursor.
+const HEIGHT: u16 = 16;
+
+/// A question for a command line tool, asked by a small inline app such as `text("Name").ask()`.
+pub struct Prompt<T> {
+    runtime: Runtime,
+    answer: Rc<RefCell<Option<T>>>,
+}
+
+impl<T: 'static> Prompt<T> {
+    fn new(answer: Rc<RefCell<Option<T>>>, app: impl Fn() -> Element + 'static) -> Self {
+        Self {
+            runtime: Runtime::new(app).inline(HEIGHT),
+            answer,
+        }
+    }
+
+    /// Asks and blocks until answered. Ctrl-C gives an `Interrupted` error.
+    pub fn ask(self) -> io::Result<T> {
+        launch(self.runtime)?;
+        take(&self.answer)
+    }
+
+    /// Asks as a future, to await inside an executor the program already has.
+    pub async fn run(self) -> io::Result<T> {
+        event_loop::run(self.runtime).await?;
+        take(&self.answer)
+    }
+
+    /// The app asking the question, to drive it some other way than the terminal.
+    pub fn runtime(&mut self) -> &mut Runtime {
+        &mut self.runtime
+    }
+
+    /// The answer, once given.
+    pub fn answer(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.answer.borrow().clone()
+    }
+}
+
+fn take<T>(answer: &Rc<RefCell<Option<T>>>) -> io::Result<T> {
+    answer
+        .borrow_mut()
+        .take()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Interrupted, "the prompt was cancelled"))
+}
+
+// Everything a prompt shows: the question, the field and a hint, or the question and its answer
+// once there is one. The answer stays in the scrollback.
+fn layout(message: &str, reply: Option<String>, field: Element, hint: &str) -> Element {
+    match reply {
+        Some(reply) => trs! { text { { format!("✔ {} · {}", message, reply) } } },
+        None => {
+            let question = trs! { text { { format!("? {}", message) } } };
+            let hint = trs! { text { { hint.to_string() } } };
+            trs! { block { { vec![question, field, hint] } } }
+        }
+    }
+}
+
+// Calls `f` with the submitted form and ends the prompt if it gives an answer.
+fn submit<T: 'static>(
+    answer: &Rc<RefCell<Option<T>>>,
+    reply: impl Fn(&str) + 'static,
+    f: impl Fn(&FormData) -> Option<(T, String)> + 'static,
+) -> impl Fn(&Event) + 'static {
+    let answer = answer.clone();
+    move |event| {
+        let Some((value, summary)) = event.form().and_then(&f) else {
+            return;
+        };
+        *answer.borrow_mut() = Some(value);
+        reply(&summary);
+        exit();
+    }
+}
+
+/// Asks for a line of text.
+pub fn text(message: &str) -> Prompt<String> {
+    let message = message.to_string();
+    let answer = Rc::default();
+    Prompt::new(Rc::clone(&answer), move || {
+        let reply = use_state(|| None::<String>);
+        let onsubmit = submit(
+            &answer,
+            move |summary| reply.set(Some(summary.to_string())),
+            |data| {
+                let text: String = data.field("answer").ok()?;
+                Some((text.clone(), text))
+            },
+        );
+        let field = trs! {
+            form { onsubmit: onsubmit, input { name: "answer" } }
+        };
+        layout(&message, reply.get(), field, "Enter to confirm")
+    })
+}
+
+/// Asks to pick one of `options`.
+pub fn select<T: Display + Clone + 'static>(
+    message: &str,
+    options: impl IntoIterator<Item = T>,
+) -> Prompt<T> {
+    let message = message.to_string();
+    let options: Vec<T> = options.into_iter().collect();
+    let labels: Vec<String> = options.iter().map(ToString::to_string).collect();
+    let answer = Rc::default();
+    Prompt::new(Rc::clone(&answer), move || {
+        let reply = use_state(|| None::<String>);
+        let (options, labels) = (options.clone(), labels.clone());
+        let items: Vec<Element> = labels
+            .iter()
+            .map(|label| trs! { item { { label.clone() } } })
+            .collect();
+        let first = labels.first().cloned().unwrap_or_default();
+        let onsubmit = submit(
+            &answer,
+            move |summary| reply.set(Some(summary.to_string())),
+            move |data| {
+                let label: String = data.field("answer").ok()?;
+                let index = labels.iter().position(|l| *l == label)?;
+                Some((options[index].clone(), label))
+            },
+        );
+        let field = trs! {
+            form { onsubmit: onsubmit, dropdown { name: "answer", value: first, { items } } }
+        };
+        layout(
+            &message,
+            reply.get(),
+            field,
+            "↑↓ to choose, Enter to confirm",
+        )
+    })
+}
+
+/// Asks to pick any number of `options`.
+pub fn multi_select<T: Display + Clone + 'static>(
+    message: &str,
+    options: impl IntoIterator<Item = T>,
+) -> Prompt<Vec<T>> {
+    let message = message.to_string();
+    let options: Vec<T> = options.into_iter().collect();
+    let answer = Rc::default();
+    Prompt::new(Rc::clone(&answer), move || {
+        let reply = use_state(|| None::<String>);
+        let boxes: Vec<Element> = options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| trs! { checkbox { name: i.to_string(), { option.to_string() } } })
+            .collect();
+        let options = options.clone();
+        let onsubmit = submit(
+            &answer,
+            move |summary| reply.set(Some(summary.to_string())),
+            move |data| {
+                let chosen: Vec<T> = options
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| data.field(&i.to_string()).unwrap_or(false))
+                    .map(|(_, option)| option.clone())
+                    .collect();
+                let summary = chosen
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Some((chosen, summary))
+            },
+        );
+        let field = trs! {
+            form { onsubmit: onsubmit, { boxes } }
+        };
+        layout(
+            &message,
+            reply.get(),
+            field,
+            "Tab to move, Space to toggle, Enter to confirm",
+        )
+    })
+}
+
+fn yes_no(text: &str) -> Option<Option<bool>> {
+    match text.trim().to_lowercase().as_str() {
+        "" => Some(None),
+        "y" | "yes" => Some(Some(true)),
+        "n" | "no" => Some(Some(false)),
+        _ => None,
+    }
+}
+
+/// Asks a yes or no question; an empty answer picks `default`.
+pub fn confirm(message: &str, default: bool) -> Prompt<bool> {
+    let message = message.to_string();
+    let hint = match default {
+        true => "y or n, Enter for yes",
+        false => "y or n, Enter for no",
+    };
+    let answer = Rc::default();
+    Prompt::new(Rc::clone(&answer), move || {
+        let reply = use_state(|| None::<String>);
+        let onsubmit = submit(
+            &answer,
+            move |summary| reply.set(Some(summary.to_string())),
+            move |data| {
+                let text: String = data.field("answer").ok()?;
+                let yes = yes_no(&text)?.unwrap_or(default);
+                Some((yes, if yes { "Yes" } else { "No" }.to_string()))
+            },
+        );
+        let field = trs! {
+            form {
+                onsubmit: onsubmit,
+                input {
+                    name: "answer",
+                    validate: |value: &AttrValue| match yes_no(&value.to_string()) {
+                        Some(_) => Ok(()),
+                        None => Err("Answer y or n".to_string()),
+                    }
+                }
+            }
+        };
+        layout(&message, reply.get(), field, hint)
+    })
+}
@@ -0,0 +1,213 @@
+use std::{cell::RefCell, rc::Rc, str::FromStr, task::Waker};
+
+use turse_core::{Element, IntoNode, Node};
+
+use crate::hooks::{keyed, use_context};
+
+type Screen = Rc<dyn Fn(&Params) -> Element>;
+
+enum Segment {
+    Static(String),
+    Param(String),
+}
+
+struct Route {
+    segments: Vec<Segment>,
+    screen: Screen,
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+impl Route {
+    fn matches(&self, path: &str) -> Option<Params> {
+        let parts: Vec<&str> = segments(path).collect();
+        if parts.len() != self.segments.len() {
+            return None;
+        }
+        let mut params = Vec::new();
+        for (segment, part) in self.segments.iter().zip(parts) {
+            match segment {
+                Segment::Static(name) if name == part => {}
+                Segment::Static(_) => return None,
+                Segment::Param(name) => params.push((name.clone(), part.to_string())),
+            }
+        }
+        Some(Params(params))
+    }
+}
+
+/// Values of the `:name` segments of the current route.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(Vec<(String, String)>);
+
+impl Params {
+    /// The parameter parsed as `T`, or `None` if it is missing or does not parse.
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.text(name).and_then(|value| value.parse().ok())
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Inner {
+    routes: Vec<Route>,
+    fallback: Option<Screen>,
+    // Visited paths, the current one last.
+    history: Vec<String>,
+    // Wakes the runtime the router was given to, so navigating from a task shows right away.
+    waker: Option<Waker>,
+}
+
+impl Inner {
+    fn wake(&self) {
+        if let Some(waker) = &self.waker {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Maps paths such as `/users/:id` to screens and keeps the history of where the app went.
+///
+/// Handed to `Runtime::router`, which renders the current screen into `router_outlet` and makes
+/// the router available to every component through `use_router`.
+#[derive(Clone)]
+pub struct Router {
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                routes: Vec::new(),
+                fallback: None,
+                history: vec!["/".to_string()],
+                waker: None,
+            })),
+        }
+    }
+
+    pub fn route(self, pattern: &str, screen: impl Fn(&Params) -> Element + 'static) -> Self {
+        let segments = segments(pattern)
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(name) => Segment::Param(name.to_string()),
+                None => Segment::Static(segment.to_string()),
+            })
+            .collect();
+        self.inner.borrow_mut().routes.push(Route {
+            segments,
+            screen: Rc::new(screen),
+        });
+        self
+    }
+
+    /// Screen for paths no route matches.
+    pub fn fallback(self, screen: impl Fn(&Params) -> Element + 'static) -> Self {
+        self.inner.borrow_mut().fallback = Some(Rc::new(screen));
+        self
+    }
+
+    /// Starts at `path` instead of `/`.
+    pub fn at(self, path: &str) -> Self {
+        self.inner.borrow_mut().history = vec![path.to_string()];
+        self
+    }
+
+    pub fn path(&self) -> String {
+        self.inner
+            .borrow()
+            .history
+            .last()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn history(&self) -> Vec<String> {
+        self.inner.borrow().history.clone()
+    }
+
+    pub fn push(&self, path: &str) {
+        let mut inner = self.inner.borrow_mut();
+        inner.history.push(path.to_string());
+        inner.wake();
+    }
+
+    /// Goes to `path` without keeping the current screen in the history.
+    pub fn replace(&self, path: &str) {
+        let mut inner = self.inner.borrow_mut();
+        inner.history.pop();
+        inner.history.push(path.to_string());
+        inner.wake();
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.inner.borrow().history.len() > 1
+    }
+
+    /// Returns to the previous screen, if there is one.
+    pub fn back(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        if inner.history.len() <= 1 {
+            return false;
+        }
+        inner.history.pop();
+        inner.wake();
+        true
+    }
+
+    pub(crate) fn wake_with(&self, waker: Waker) {
+        self.inner.borrow_mut().waker = Some(waker);
+    }
+
+    fn screen(&self) -> Node {
+        let path = self.path();
+        let matched = {
+            let inner = self.inner.borrow();
+            inner
+                .routes
+                .iter()
+                .find_map(|route| Some((route.screen.clone(), route.matches(&path)?)))
+                .or_else(|| Some((inner.fallback.clone()?, Params::default())))
+        };
+        // Every path gets fresh hooks, so `/users/1` and `/users/2` do not share state.
+        let element = match matched {
+            Some((screen, params)) => keyed(&format!("route:{}", path), || screen(&params)),
+            None => Element::empty(),
+        };
+        element.into_inner_node()
+    }
+
+    // Renders the current screen as the content of every `router_outlet` not filled in yet.
+    pub(crate) fn expand(&self, node: &mut Node) {
+        if let Node::Element { tag, children, .. } = node {
+            if tag == "router_outlet" {
+                if children.is_empty() {
+                    *children = vec![self.screen()];
+                }
+            } else {
+                for child in children {
+                    self.expand(child);
+                }
+            }
+        }
+    }
+}
+
+/// The router given to `Runtime::router`.
+#[track_caller]
+pub fn use_router() -> Router {
+    use_context()
+}
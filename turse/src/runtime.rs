@@ -0,0 +1,548 @@
+use std::{cell::Cell, collections::HashMap, rc::Rc, task::Waker, time::Duration};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{
+        Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::{Position, Rect},
+};
+use turse_core::{AttrValue, Element, Event as ElementEvent, Node};
+
+use crate::{
+    command::{self, Command},
+    dialog::Dialog,
+    hooks::{self, provide_context, Hooks},
+    keymap::{Keymap, Step},
+    node_ref::node_ref,
+    router::Router,
+    scroll::ScrollState,
+    state::StateMap,
+    task::Executor,
+    toast::{Corner, Toasts},
+    widget::{child_width, measure_node, render_layers, render_node, Attrs, Ctx, Mounted, Mounts},
+    widgets,
+};
+
+const WHEEL_STEP: isize = 3;
+
+pub struct Runtime {
+    app: Box<dyn Fn() -> Element>,
+    state: StateMap,
+    mounts: Mounts,
+    focused: Option<String>,
+    pressed: Option<String>,
+    // Elements under the mouse, innermost first.
+    hovered: Vec<String>,
+    dragging: Option<String>,
+    // Focus to return to once the open modal closes.
+    restore_focus: Option<String>,
+    dialogs: Vec<Dialog>,
+    toasts: Toasts,
+    keymap: Keymap,
+    help: Rc<Cell<bool>>,
+    hooks: Hooks,
+    executor: Executor,
+    router: Option<Router>,
+    inline: Option<u16>,
+    content_height: u16,
+    exited: bool,
+}
+
+impl<F: Fn() -> Element + 'static> From<F> for Runtime {
+    fn from(app: F) -> Self {
+        Runtime::new(app)
+    }
+}
+
+impl Runtime {
+    pub fn new(app: impl Fn() -> Element + 'static) -> Self {
+        let executor = Executor::default();
+        Self {
+            app: Box::new(app),
+            state: StateMap::default(),
+            mounts: Mounts::default(),
+            focused: None,
+            pressed: None,
+            hovered: Vec::new(),
+            dragging: None,
+            restore_focus: None,
+            dialogs: Vec::new(),
+            toasts: Toasts::new(executor.waker()),
+            keymap: Keymap::default(),
+            help: Rc::default(),
+            hooks: Hooks::default(),
+            executor,
+            router: None,
+            inline: None,
+            content_height: 0,
+            exited: false,
+        }
+    }
+
+    /// Sets the corner of the screen that notifications stack up from.
+    pub fn toast_corner(mut self, corner: Corner) -> Self {
+        self.toasts.corner = corner;
+        self
+    }
+
+    /// Sets how long each notification stays on screen.
+    pub fn toast_timeout(mut self, timeout: Duration) -> Self {
+        self.toasts.timeout = timeout;
+        self
+    }
+
+    /// Sets the app level key bindings, listed by pressing `?`.
+    pub fn keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Sets the screens shown in `router_outlet`.
+    pub fn router(mut self, router: Router) -> Self {
+        router.wake_with(self.executor.waker());
+        self.router = Some(router);
+        self
+    }
+
+    /// Renders below the cursor instead of taking over the screen, in as many lines as the app
+    /// needs up to `height`. The last frame stays in the scrollback once the app ends.
+    pub fn inline(mut self, height: u16) -> Self {
+        self.inline = Some(height);
+        self
+    }
+
+    pub(crate) fn inline_height(&self) -> Option<u16> {
+        self.inline
+    }
+
+    /// Rows the app asked for in the last render, at the width it was given.
+    pub fn content_height(&self) -> u16 {
+        self.content_height
+    }
+
+    pub(crate) fn is_exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Whether a task or store update changed something the screen does not show yet.
+    pub fn needs_redraw(&self) -> bool {
+        self.executor.is_woken()
+    }
+
+    // Wakes the runtime from tasks that run outside its executor.
+    #[cfg(any(feature = "tokio", feature = "smol"))]
+    pub(crate) fn waker(&self) -> Waker {
+        self.executor.waker()
+    }
+
+    pub(crate) fn wake_on_progress(&self, waker: &Waker) {
+        self.executor.listen(waker);
+    }
+
+    pub fn focused(&self) -> Option<&str> {
+        self.focused.as_deref()
+    }
+
+    pub fn render(&mut self, area: Rect, buf: &mut Buffer) {
+        self.executor.poll();
+        let pending = self.apply_commands();
+        self.draw(area, buf);
+
+        // Elements that only appeared in this frame can be revealed now that they are laid out.
+        let mut revealed = false;
+        for command in pending {
+            if let Command::ScrollIntoView { key } = command {
+                revealed |= self.reveal(&key);
+            }
+        }
+        if revealed {
+            self.draw(area, buf);
+        }
+
+        self.toasts.update();
+        self.toasts.render(area, buf);
+    }
+
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Tab => self.cycle_focus(1),
+                KeyCode::BackTab => self.cycle_focus(-1),
+                _ => {
+                    // Chords, from their first key on, go to the keymap before the focused
+                    // element sees them. A key that breaks the chord is then handled as if it
+                    // came on its own.
+                    let chord = self.keymap.is_pending()
+                        || self
+                            .keymap
+                            .starts_chord(*key, &self.scopes(), self.global_keys());
+                    if chord && self.run_binding(*key) {
+                        return true;
+                    }
+                    let handled = self
+                        .focused
+                        .as_deref()
+                        .and_then(|k| self.mounts.get(k))
+                        .is_some_and(|m| widgets::get(&m.tag).on_key(m, *key, &mut self.state))
+                        || self.run_binding(*key);
+                    if !handled && key.code == KeyCode::Char('?') && !self.keymap.is_empty() {
+                        self.help.set(!self.help.get());
+                        return true;
+                    }
+                    // Keys nothing else wanted go to the open modal, e.g. Escape to dismiss it.
+                    handled
+                        || self.mounts.trap.is_some_and(|i| {
+                            let modal = &self.mounts.list[i];
+                            widgets::get(&modal.tag).on_key(modal, *key, &mut self.state)
+                        })
+                }
+            },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
+            _ => false,
+        }
+    }
+
+    fn run_binding(&mut self, key: KeyEvent) -> bool {
+        match self.keymap.feed(key, &self.scopes(), self.global_keys()) {
+            Step::Run(handler) => {
+                handler();
+                true
+            }
+            Step::Pending => true,
+            Step::Miss => false,
+        }
+    }
+
+    // Ids of the elements around the focused one, innermost first, up to the open modal if any.
+    fn scopes(&self) -> Vec<String> {
+        let Some(focused) = self.focused.as_deref() else {
+            return Vec::new();
+        };
+        let modal = self.mounts.trap.map(|i| self.mounts.list[i].key.as_str());
+        let mut ancestors: Vec<&Mounted> = self.mounts.ancestors(focused).collect();
+        if let Some(i) = ancestors.iter().position(|m| Some(m.key.as_str()) == modal) {
+            ancestors.truncate(i + 1);
+        }
+        ancestors
+            .into_iter()
+            .filter_map(|m| m.attrs.text("id"))
+            .collect()
+    }
+
+    // Unscoped bindings belong to the app behind an open modal or dialog.
+    fn global_keys(&self) -> bool {
+        self.mounts.trap.is_none()
+    }
+
+    fn help(&self) -> Node {
+        let bindings = self.keymap.active(&self.scopes(), self.global_keys());
+        let width = bindings
+            .iter()
+            .map(|(keys, _)| keys.chars().count())
+            .max()
+            .unwrap_or(0);
+        let line = bindings
+            .iter()
+            .map(|(_, description)| width + 2 + description.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = bindings
+            .into_iter()
+            .map(|(keys, description)| Node::Element {
+                tag: "text".to_string(),
+                attrs: HashMap::new(),
+                children: vec![Node::Body(format!(
+                    "{:<width$}  {}",
+                    keys,
+                    description,
+                    width = width
+                ))],
+            })
+            .collect();
+        let help = self.help.clone();
+        Node::Element {
+            tag: "modal".to_string(),
+            attrs: HashMap::from([
+                ("title".to_string(), AttrValue::Text("Keys".to_string())),
+                ("width".to_string(), AttrValue::Int(line as i64 + 2)),
+                (
+                    "ondismiss".to_string(),
+                    AttrValue::handler(move |_| help.set(false)),
+                ),
+            ]),
+            children: rows,
+        }
+    }
+
+    fn handle_mouse(&mut self, mouse: &MouseEvent) -> bool {
+        let (x, y) = (mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollUp => {
+                let delta = match mouse.kind {
+                    MouseEventKind::ScrollDown => WHEEL_STEP,
+                    _ => -WHEEL_STEP,
+                };
+                self.mounts.at(x, y).any(|m| {
+                    if m.attrs.contains_key("onwheel") {
+                        m.attrs.emit("onwheel", ElementEvent::Wheel(delta));
+                        return true;
+                    }
+                    widgets::get(&m.tag).on_scroll(m, delta, &mut self.state)
+                })
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let focusable = &self.mounts.focusable;
+                if let Some(target) = self.mounts.at(x, y).find(|m| focusable.contains(&m.key)) {
+                    self.focused = Some(target.key.clone());
+                }
+                self.pressed = self.mounts.at(x, y).next().map(|m| m.key.clone());
+                self.dragging = self
+                    .mounts
+                    .at(x, y)
+                    .find(|m| {
+                        ["ondragstart", "ondrag", "ondragend"]
+                            .iter()
+                            .any(|name| m.attrs.contains_key(*name))
+                    })
+                    .map(|m| m.key.clone());
+                self.emit_drag("ondragstart", x, y);
+                self.pressed.is_some()
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let dragged = self.emit_drag("ondrag", x, y);
+                self.hover(x, y) || dragged
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let dragged = self.emit_drag("ondragend", x, y);
+                self.dragging = None;
+                let Some(pressed) = self.pressed.take() else {
+                    return dragged;
+                };
+                let inside = self
+                    .mounts
+                    .get(&pressed)
+                    .is_some_and(|m| m.rect.contains(Position::new(x, y)));
+                if inside {
+                    // Clicks go to the innermost element that handles them.
+                    self.mounts.at(x, y).any(|m| {
+                        let position = Position::new(x - m.rect.x, y - m.rect.y);
+                        widgets::get(&m.tag).on_click(m, position, &mut self.state)
+                    });
+                }
+                true
+            }
+            MouseEventKind::Moved => self.hover(x, y),
+            _ => false,
+        }
+    }
+
+    fn hover(&mut self, x: u16, y: u16) -> bool {
+        let hovered: Vec<String> = self.mounts.at(x, y).map(|m| m.key.clone()).collect();
+        if hovered == self.hovered {
+            return false;
+        }
+        let previous = std::mem::replace(&mut self.hovered, hovered);
+        for key in previous.iter().filter(|key| !self.hovered.contains(key)) {
+            if let Some(m) = self.mounts.get(key) {
+                m.attrs.emit("onmouseleave", mouse_event(m, x, y));
+            }
+        }
+        for key in self.hovered.iter().filter(|key| !previous.contains(key)) {
+            if let Some(m) = self.mounts.get(key) {
+                m.attrs.emit("onmouseenter", mouse_event(m, x, y));
+            }
+        }
+        true
+    }
+
+    fn emit_drag(&self, name: &str, x: u16, y: u16) -> bool {
+        let Some(m) = self
+            .dragging
+            .as_deref()
+            .and_then(|key| self.mounts.get(key))
+        else {
+            return false;
+        };
+        m.attrs.emit(name, mouse_event(m, x, y));
+        true
+    }
+
+    fn draw(&mut self, area: Rect, buf: &mut Buffer) {
+        let router = self.router.clone();
+        let element = hooks::render(&mut self.hooks, self.executor.waker(), || {
+            let Some(router) = router else {
+                return (self.app)();
+            };
+            provide_context(router.clone());
+            let mut element = (self.app)();
+            if let Some(node) = &mut element.inner {
+                router.expand(node);
+            }
+            element
+        });
+        let help = self.help.get().then(|| self.help());
+        self.dialogs.retain(|dialog| !dialog.is_settled());
+        let mut mounts = Mounts::default();
+        {
+            let mut ctx = Ctx::new(&mut self.state, &mut mounts, area);
+            ctx.focused = self.focused.as_deref();
+            ctx.pressed = self.pressed.as_deref();
+            ctx.hovered = &self.hovered;
+            if let Some(node) = &element.inner {
+                let width = child_width(node, area.width);
+                self.content_height = measure_node(node, "root".to_string(), width, &mut ctx);
+                render_node(node, "root".to_string(), area, buf, &mut ctx);
+            } else {
+                self.content_height = 0;
+            }
+            let origin = Rect::new(area.x, area.y, 0, 0);
+            for dialog in &self.dialogs {
+                render_node(&dialog.node(), dialog.key.clone(), origin, buf, &mut ctx);
+            }
+            if let Some(help) = &help {
+                render_node(help, "keymap:help".to_string(), origin, buf, &mut ctx);
+            }
+            render_layers(area, buf, &mut ctx);
+        }
+
+        self.state.retain(|key| mounts.keeps(key));
+        if mounts.trap.is_some() && self.mounts.trap.is_none() {
+            self.restore_focus = self.focused.clone();
+        }
+        if !self
+            .focused
+            .as_ref()
+            .is_some_and(|key| mounts.focusable.contains(key))
+        {
+            let restored = match mounts.trap {
+                Some(_) => None,
+                None => self.restore_focus.take(),
+            };
+            self.focused = restored
+                .filter(|key| mounts.focusable.contains(key))
+                .or_else(|| mounts.focusable.first().cloned());
+        }
+        if !self
+            .pressed
+            .as_ref()
+            .is_some_and(|key| mounts.contains(key))
+        {
+            self.pressed = None;
+        }
+        self.lifecycle(&mounts);
+        self.mounts = mounts;
+    }
+
+    fn lifecycle(&self, mounts: &Mounts) {
+        let (before, after) = (latest(&self.mounts), latest(mounts));
+        // A different kind of element in the same place is a new element.
+        let same = |a: &Mounted, b: &Mounted| a.tag == b.tag;
+
+        for m in self.mounts.list.iter().filter(|m| is_latest(&before, m)) {
+            if !after.get(m.key.as_str()).is_some_and(|new| same(m, new)) {
+                if let Some(node_ref) = node_ref(&m.attrs) {
+                    node_ref.unbind(&m.key);
+                }
+                m.attrs.emit("onunmount", ElementEvent::Unmount);
+            }
+        }
+        for m in mounts.list.iter().filter(|m| is_latest(&after, m)) {
+            let previous = before.get(m.key.as_str()).filter(|old| same(old, m));
+            if previous.is_none() {
+                m.attrs.emit("onmount", ElementEvent::Mount);
+            }
+            if previous.is_none_or(|previous| previous.rect != m.rect) {
+                m.attrs.emit(
+                    "onresize",
+                    ElementEvent::Resize {
+                        x: m.rect.x,
+                        y: m.rect.y,
+                        width: m.rect.width,
+                        height: m.rect.height,
+                    },
+                );
+            }
+        }
+    }
+
+    fn cycle_focus(&mut self, step: isize) -> bool {
+        let focusable = &self.mounts.focusable;
+        if focusable.is_empty() {
+            return false;
+        }
+        let len = focusable.len() as isize;
+        let next = match self
+            .focused
+            .as_ref()
+            .and_then(|key| focusable.iter().position(|k| k == key))
+        {
+            Some(i) => (i as isize + step).rem_euclid(len),
+            None => 0,
+        };
+        self.focused = Some(focusable[next as usize].clone());
+        true
+    }
+
+    fn apply_commands(&mut self) -> Vec<Command> {
+        let mut pending = Vec::new();
+        for command in command::drain() {
+            match command {
+                Command::ScrollTo { id, offset } => {
+                    self.state.get::<ScrollState>(&format!("#{}", id)).offset = offset;
+                }
+                Command::ScrollIntoView { ref key } => {
+                    if !self.reveal(key) {
+                        pending.push(command);
+                    }
+                }
+                Command::Focus { key } => {
+                    if self.mounts.focusable.contains(&key) {
+                        self.focused = Some(key);
+                    }
+                }
+                Command::Call { key, method } => {
+                    if let Some(m) = self.mounts.get(&key) {
+                        widgets::get(&m.tag).call(m, &method, &mut self.state);
+                    }
+                }
+                Command::ShowDialog(dialog) => self.dialogs.push(dialog),
+                Command::Exit => self.exited = true,
+            }
+        }
+        pending
+    }
+
+    fn reveal(&mut self, key: &str) -> bool {
+        let Some(mut mounted) = self.mounts.get(key) else {
+            return false;
+        };
+        while let Some(parent) = &mounted.scroll_parent {
+            let scroll = self.state.get::<ScrollState>(parent);
+            scroll.reveal(mounted.content.start, mounted.content.len());
+            match self.mounts.get(parent) {
+                Some(next) => mounted = next,
+                None => break,
+            }
+        }
+        true
+    }
+}
+
+// Overlays are mounted twice, in the flow and as a layer; the layer comes last and wins.
+fn latest(mounts: &Mounts) -> HashMap<&str, &Mounted> {
+    mounts.list.iter().map(|m| (m.key.as_str(), m)).collect()
+}
+
+fn is_latest(latest: &HashMap<&str, &Mounted>, m: &Mounted) -> bool {
+    latest
+        .get(m.key.as_str())
+        .is_some_and(|last| std::ptr::eq(*last, m))
+}
+
+fn mouse_event(mounted: &Mounted, x: u16, y: u16) -> ElementEvent {
+    ElementEvent::Mouse {
+        x: x as i32 - mounted.rect.x as i32,
+        y: y as i32 - mounted.rect.y as i32,
+    }
+}
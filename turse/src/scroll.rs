@@ -0,0 +1,85 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget},
+};
+
+use crate::command::{self, Command};
+
+#[derive(Default)]
+pub(crate) struct ScrollState {
+    pub offset: usize,
+    pub viewport: usize,
+    pub content: usize,
+}
+
+impl ScrollState {
+    pub fn max_offset(&self) -> usize {
+        self.content.saturating_sub(self.viewport)
+    }
+
+    pub fn resize(&mut self, viewport: usize, content: usize) {
+        self.viewport = viewport;
+        self.content = content;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) -> bool {
+        let offset = self
+            .offset
+            .saturating_add_signed(delta)
+            .min(self.max_offset());
+        let changed = offset != self.offset;
+        self.offset = offset;
+        changed
+    }
+
+    // Scrolls the least amount needed for rows `top..top + height` to be visible.
+    pub fn reveal(&mut self, top: usize, height: usize) {
+        let bottom = top.saturating_add(height);
+        if top < self.offset || height > self.viewport {
+            self.offset = top;
+        } else if bottom > self.offset + self.viewport {
+            self.offset = bottom - self.viewport;
+        }
+    }
+
+    pub fn on_key(&mut self, key: KeyEvent) -> bool {
+        let page = self.viewport.saturating_sub(1).max(1) as isize;
+        match key.code {
+            KeyCode::Up => self.scroll_by(-1),
+            KeyCode::Down => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::PageDown => self.scroll_by(page),
+            KeyCode::Home => self.scroll_by(-(self.offset as isize)),
+            KeyCode::End => self.scroll_by(self.max_offset() as isize),
+            _ => false,
+        }
+    }
+}
+
+pub(crate) fn render_scrollbar(area: Rect, buf: &mut Buffer, scroll: &ScrollState) {
+    if area.is_empty() {
+        return;
+    }
+    let mut state = ScrollbarState::new(scroll.max_offset())
+        .position(scroll.offset)
+        .viewport_content_length(scroll.viewport);
+    Scrollbar::new(ScrollbarOrientation::VerticalRight).render(area, buf, &mut state);
+}
+
+/// Scrolls the container with the given `id` so that its content starts `offset` rows down.
+pub fn scroll_to(id: impl Into<String>, offset: usize) {
+    command::push(Command::ScrollTo {
+        id: id.into(),
+        offset,
+    });
+}
+
+/// Scrolls every container around the element with the given `id` until it is visible.
+pub fn scroll_into_view(id: impl Into<String>) {
+    command::push(Command::ScrollIntoView {
+        key: format!("#{}", id.into()),
+    });
+}
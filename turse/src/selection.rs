@@ -0,0 +1,42 @@
+use ratatui::crossterm::event::{KeyCode, KeyEvent};
+
+#[derive(Default)]
+pub(crate) struct Selection {
+    pub cursor: Option<usize>,
+}
+
+impl Selection {
+    pub fn clamp(&mut self, count: usize) {
+        self.cursor = match self.cursor {
+            _ if count == 0 => None,
+            Some(cursor) => Some(cursor.min(count - 1)),
+            None => None,
+        };
+    }
+
+    pub fn select(&mut self, index: usize) -> bool {
+        let changed = self.cursor != Some(index);
+        self.cursor = Some(index);
+        changed
+    }
+
+    // Moves the cursor for navigation keys, returning whether it moved.
+    pub fn on_key(&mut self, key: KeyEvent, count: usize, page: usize) -> bool {
+        if count == 0 {
+            return false;
+        }
+        let last = count - 1;
+        let page = page.max(1);
+        let index = match (key.code, self.cursor) {
+            (KeyCode::Up, Some(i)) => i.saturating_sub(1),
+            (KeyCode::Down, Some(i)) => (i + 1).min(last),
+            (KeyCode::PageUp, Some(i)) => i.saturating_sub(page),
+            (KeyCode::PageDown, Some(i)) => (i + page).min(last),
+            (KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown, None) => 0,
+            (KeyCode::Home, _) => 0,
+            (KeyCode::End, _) => last,
+            _ => return false,
+        };
+        self.select(index)
+    }
+}
@@ -0,0 +1,23 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+#[derive(Default)]
+pub(crate) struct StateMap {
+    map: HashMap<(String, TypeId), Box<dyn Any>>,
+}
+
+impl StateMap {
+    pub fn get<T: Default + 'static>(&mut self, key: &str) -> &mut T {
+        self.map
+            .entry((key.to_string(), TypeId::of::<T>()))
+            .or_insert_with(|| Box::new(T::default()))
+            .downcast_mut()
+            .unwrap()
+    }
+
+    pub fn retain(&mut self, keep: impl Fn(&str) -> bool) {
+        self.map.retain(|(key, _), _| keep(key));
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    sync::{Arc, Mutex, RwLock, RwLockReadGuard, Weak},
+    task::Waker,
+};
+
+use crate::hooks::{current_waker, use_ref};
+
+trait Subscriber<T>: Send + Sync {
+    fn notify(&self, value: &T);
+}
+
+struct Selection<T, S> {
+    select: Box<dyn Fn(&T) -> S + Send + Sync>,
+    slice: Mutex<S>,
+    waker: Mutex<Waker>,
+}
+
+impl<T, S: PartialEq + Send> Subscriber<T> for Selection<T, S> {
+    // Only a slice that actually changed asks the runtime for another frame.
+    fn notify(&self, value: &T) {
+        let slice = (self.select)(value);
+        let mut current = self.slice.lock().unwrap();
+        if *current != slice {
+            *current = slice;
+            self.waker.lock().unwrap().wake_by_ref();
+        }
+    }
+}
+
+struct Inner<T> {
+    value: RwLock<T>,
+    subscribers: Mutex<Vec<Weak<dyn Subscriber<T>>>>,
+}
+
+/// App wide state shared across components, async tasks and threads.
+pub struct Store<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Default for Store<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Send + Sync + 'static> Store<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                value: RwLock::new(value),
+                subscribers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.inner.value.read().unwrap()
+    }
+
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.read().clone()
+    }
+
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value);
+    }
+
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.inner.value.write().unwrap());
+        let subscribers: Vec<_> = {
+            let mut subscribers = self.inner.subscribers.lock().unwrap();
+            subscribers.retain(|s| s.strong_count() > 0);
+            subscribers.iter().filter_map(Weak::upgrade).collect()
+        };
+        let value = self.read();
+        for subscriber in subscribers {
+            subscriber.notify(&value);
+        }
+    }
+
+    fn subscribe(&self, subscriber: Weak<dyn Subscriber<T>>) {
+        self.inner.subscribers.lock().unwrap().push(subscriber);
+    }
+}
+
+/// Reads a slice of the store; the app is redrawn for a store update only when a slice it
+/// selected changed.
+///
+/// The selector given on the first render is kept for the life of the component.
+#[track_caller]
+pub fn use_selector<T, S>(store: &Store<T>, select: impl Fn(&T) -> S + Send + Sync + 'static) -> S
+where
+    T: Send + Sync + 'static,
+    S: PartialEq + Clone + Send + 'static,
+{
+    let waker = current_waker();
+    let selection = use_ref(|| {
+        let selection: Arc<Selection<T, S>> = Arc::new(Selection {
+            slice: Mutex::new(select(&store.read())),
+            select: Box::new(select),
+            waker: Mutex::new(waker.clone()),
+        });
+        let subscriber: Arc<dyn Subscriber<T>> = selection.clone();
+        store.subscribe(Arc::downgrade(&subscriber));
+        selection
+    });
+    let selection = selection.borrow();
+    let mut current = selection.waker.lock().unwrap();
+    if !current.will_wake(&waker) {
+        *current = waker;
+    }
+    selection.slice.lock().unwrap().clone()
+}
@@ -0,0 +1,345 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use futures_util::{
+    future::{abortable, AbortHandle, LocalBoxFuture},
+    stream::{FuturesUnordered, StreamExt},
+    task::{waker, ArcWake},
+    FutureExt,
+};
+
+#[cfg(all(feature = "smol", not(feature = "tokio")))]
+use std::rc::Rc;
+
+thread_local! {
+    static SPAWNED: RefCell<Vec<LocalBoxFuture<'static, ()>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Handle to a spawned task.
+#[derive(Clone)]
+pub struct Task {
+    handle: AbortHandle,
+}
+
+impl Task {
+    /// Stops the task at its next await point.
+    pub fn cancel(&self) {
+        self.handle.abort();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.handle.is_aborted()
+    }
+}
+
+/// Runs a future on the runtime's executor, polled between frames on the UI thread.
+///
+/// Inside `launch` or `run` with the `tokio` or `smol` feature the task runs on that executor
+/// instead, as a local task on the UI thread.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> Task {
+    let (future, handle) = abortable(future);
+    let future = future.map(|_| ());
+    #[cfg(any(feature = "tokio", feature = "smol"))]
+    if let Some(host) = HOST.with(|h| h.borrow().clone()) {
+        host.spawn(future);
+        return Task { handle };
+    }
+    SPAWNED.with(|s| s.borrow_mut().push(future.boxed_local()));
+    Task { handle }
+}
+
+#[cfg(any(feature = "tokio", feature = "smol"))]
+thread_local! {
+    static HOST: RefCell<Option<Host>> = const { RefCell::new(None) };
+}
+
+// The executor `run` drives the app on, set while the app or one of its tasks is polled.
+#[cfg(any(feature = "tokio", feature = "smol"))]
+#[derive(Clone)]
+struct Host {
+    // Wakes the runtime, so the next frame shows what a task did.
+    waker: Waker,
+    #[cfg(all(feature = "smol", not(feature = "tokio")))]
+    local: Rc<smol::LocalExecutor<'static>>,
+}
+
+#[cfg(any(feature = "tokio", feature = "smol"))]
+impl Host {
+    fn spawn(&self, future: impl Future<Output = ()> + 'static) {
+        let waker = self.waker.clone();
+        let mut future = hosted(self.clone(), future).boxed_local();
+        let task = std::future::poll_fn(move |cx| {
+            let poll = future.poll_unpin(cx);
+            waker.wake_by_ref();
+            poll
+        });
+        #[cfg(feature = "tokio")]
+        tokio::task::spawn_local(task);
+        #[cfg(all(feature = "smol", not(feature = "tokio")))]
+        self.local.spawn(task).detach();
+    }
+}
+
+// Puts back the previous host, also when polling panics.
+#[cfg(any(feature = "tokio", feature = "smol"))]
+struct Restore(Option<Host>);
+
+#[cfg(any(feature = "tokio", feature = "smol"))]
+impl Drop for Restore {
+    fn drop(&mut self) {
+        HOST.with(|h| *h.borrow_mut() = self.0.take());
+    }
+}
+
+#[cfg(any(feature = "tokio", feature = "smol"))]
+fn hosted<T>(host: Host, future: impl Future<Output = T>) -> impl Future<Output = T> {
+    let mut future = Box::pin(future);
+    std::future::poll_fn(move |cx| {
+        let _restore = Restore(HOST.with(|h| h.replace(Some(host.clone()))));
+        future.as_mut().poll(cx)
+    })
+}
+
+/// Drives `future`, the event loop of a runtime with the given waker, so that tasks it spawns
+/// run on the executor of the enabled feature.
+#[cfg(feature = "tokio")]
+pub(crate) async fn host<T>(waker: Waker, future: impl Future<Output = T>) -> T {
+    let tasks = tokio::task::LocalSet::new();
+    tasks.run_until(hosted(Host { waker }, future)).await
+}
+
+#[cfg(all(feature = "smol", not(feature = "tokio")))]
+pub(crate) async fn host<T>(waker: Waker, future: impl Future<Output = T>) -> T {
+    let local = Rc::new(smol::LocalExecutor::new());
+    let host = Host {
+        waker,
+        local: local.clone(),
+    };
+    local.run(hosted(host, future)).await
+}
+
+/// Runs blocking work such as a synchronous HTTP request off the UI thread.
+///
+/// With the `tokio` or `smol` feature this uses that executor's blocking pool while the app runs
+/// on it, otherwise a thread of its own.
+pub async fn spawn_blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    #[cfg(feature = "tokio")]
+    if on_executor() {
+        return tokio::task::spawn_blocking(f)
+            .await
+            .expect("blocking task panicked");
+    }
+    #[cfg(all(feature = "smol", not(feature = "tokio")))]
+    if on_executor() {
+        return smol::unblock(f).await;
+    }
+    on_thread(f).await
+}
+
+/// Completes after the given duration, using the timer of the enabled executor feature while
+/// the app runs on it.
+pub async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio")]
+    if on_executor() {
+        return tokio::time::sleep(duration).await;
+    }
+    #[cfg(all(feature = "smol", not(feature = "tokio")))]
+    if on_executor() {
+        smol::Timer::after(duration).await;
+        return;
+    }
+    Sleep::new(duration).await;
+}
+
+// Whether the executor of the enabled feature is there to run timers and blocking work, which
+// it is not for a runtime driven by hand, as in tests.
+#[cfg(feature = "tokio")]
+fn on_executor() -> bool {
+    tokio::runtime::Handle::try_current().is_ok()
+}
+
+#[cfg(all(feature = "smol", not(feature = "tokio")))]
+fn on_executor() -> bool {
+    HOST.with(|h| h.borrow().is_some())
+}
+
+// Sleeping tasks, woken by a single timer thread once their deadline passes.
+#[derive(Default)]
+struct Timers {
+    sleepers: Mutex<BTreeMap<(Instant, u64), Waker>>,
+    changed: Condvar,
+}
+
+static TIMERS: OnceLock<Timers> = OnceLock::new();
+
+impl Timers {
+    fn get() -> &'static Timers {
+        let mut started = false;
+        let timers = TIMERS.get_or_init(|| {
+            started = true;
+            Timers::default()
+        });
+        if started {
+            std::thread::spawn(move || timers.run());
+        }
+        timers
+    }
+
+    fn run(&self) {
+        let mut sleepers = self.sleepers.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            while let Some(sleeper) = sleepers.first_entry().filter(|s| s.key().0 <= now) {
+                sleeper.remove().wake();
+            }
+            sleepers = match sleepers.keys().next() {
+                Some(&(deadline, _)) => {
+                    let timeout = deadline.saturating_duration_since(now);
+                    self.changed.wait_timeout(sleepers, timeout).unwrap().0
+                }
+                None => self.changed.wait(sleepers).unwrap(),
+            };
+        }
+    }
+}
+
+struct Sleep {
+    deadline: Instant,
+    id: u64,
+}
+
+impl Sleep {
+    fn new(duration: Duration) -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Sleep {
+            deadline: Instant::now() + duration,
+            id: NEXT.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let timers = Timers::get();
+        let key = (self.deadline, self.id);
+        timers
+            .sleepers
+            .lock()
+            .unwrap()
+            .insert(key, cx.waker().clone());
+        timers.changed.notify_one();
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some(timers) = TIMERS.get() {
+            let key = (self.deadline, self.id);
+            timers.sleepers.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+struct Blocking<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+fn on_thread<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> impl Future<Output = T> {
+    let shared = Arc::new(Mutex::new(Blocking {
+        result: None,
+        waker: None,
+    }));
+    let worker = shared.clone();
+    std::thread::spawn(move || {
+        let result = f();
+        let mut worker = worker.lock().unwrap();
+        worker.result = Some(result);
+        if let Some(waker) = worker.waker.take() {
+            waker.wake();
+        }
+    });
+    std::future::poll_fn(move |cx| {
+        let mut shared = shared.lock().unwrap();
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct Wakeup {
+    woken: AtomicBool,
+    // The event loop waiting for the next frame, when it runs as a future itself.
+    listener: Mutex<Option<Waker>>,
+}
+
+impl ArcWake for Wakeup {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.woken.store(true, Ordering::Release);
+        if let Some(waker) = arc_self.listener.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Executor {
+    tasks: FuturesUnordered<LocalBoxFuture<'static, ()>>,
+    wakeup: Arc<Wakeup>,
+}
+
+impl Executor {
+    // Whether a task made progress since the last poll and the screen may be stale.
+    pub fn is_woken(&self) -> bool {
+        self.wakeup.woken.load(Ordering::Acquire) || SPAWNED.with(|s| !s.borrow().is_empty())
+    }
+
+    pub fn waker(&self) -> Waker {
+        waker(self.wakeup.clone())
+    }
+
+    pub fn listen(&self, waker: &Waker) {
+        *self.wakeup.listener.lock().unwrap() = Some(waker.clone());
+    }
+
+    pub fn poll(&mut self) {
+        self.wakeup.woken.store(false, Ordering::Release);
+        let waker = waker(self.wakeup.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut spawned = take_spawned();
+        // Tasks may spawn more tasks while they are polled.
+        loop {
+            self.tasks.extend(spawned);
+            while let Poll::Ready(Some(())) = self.tasks.poll_next_unpin(&mut cx) {}
+            spawned = take_spawned();
+            if spawned.is_empty() {
+                return;
+            }
+        }
+    }
+}
+
+fn take_spawned() -> Vec<LocalBoxFuture<'static, ()>> {
+    SPAWNED.with(|s| std::mem::take(&mut *s.borrow_mut()))
+}
@@ -0,0 +1,181 @@
+use std::{
+    cell::RefCell,
+    sync::{Arc, Mutex, MutexGuard, Weak},
+    task::Waker,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Widget as _},
+};
+
+use crate::widgets::text::{render_lines, wrap};
+
+const MAX_WIDTH: u16 = 40;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Level {
+    fn title(self) -> &'static str {
+        match self {
+            Level::Info => "Info",
+            Level::Success => "Success",
+            Level::Warning => "Warning",
+            Level::Error => "Error",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Level::Info => Color::Blue,
+            Level::Success => Color::Green,
+            Level::Warning => Color::Yellow,
+            Level::Error => Color::Red,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+pub(crate) struct Toast {
+    level: Level,
+    message: String,
+    expires: Option<Instant>,
+}
+
+// What was sent to one runtime, and how to ask it for another frame.
+struct Inbox {
+    messages: Mutex<Vec<(Level, String)>>,
+    waker: Waker,
+}
+
+// Sent from threads without a runtime, for whichever runtime updates first.
+static QUEUE: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+static INBOXES: Mutex<Vec<Weak<Inbox>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // The runtime that last rendered on this thread.
+    static LOCAL: RefCell<Weak<Inbox>> = const { RefCell::new(Weak::new()) };
+}
+
+/// Shows a message in a corner of the screen for a few seconds.
+pub fn notify(level: Level, message: impl Into<String>) {
+    let message = message.into();
+    if let Some(inbox) = LOCAL.with(|l| l.borrow().upgrade()) {
+        lock(&inbox.messages).push((level, message));
+        inbox.waker.wake_by_ref();
+        return;
+    }
+    lock(&QUEUE).push((level, message));
+    for inbox in lock(&INBOXES).iter().filter_map(Weak::upgrade) {
+        inbox.waker.wake_by_ref();
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+pub(crate) struct Toasts {
+    pub corner: Corner,
+    pub timeout: Duration,
+    list: Vec<Toast>,
+    inbox: Arc<Inbox>,
+}
+
+impl Toasts {
+    pub fn new(waker: Waker) -> Self {
+        let inbox = Arc::new(Inbox {
+            messages: Mutex::new(Vec::new()),
+            waker,
+        });
+        let mut inboxes = lock(&INBOXES);
+        inboxes.retain(|inbox| inbox.strong_count() > 0);
+        inboxes.push(Arc::downgrade(&inbox));
+        LOCAL.with(|l| *l.borrow_mut() = Arc::downgrade(&inbox));
+        Self {
+            corner: Corner::BottomRight,
+            timeout: Duration::from_secs(4),
+            list: Vec::new(),
+            inbox,
+        }
+    }
+}
+
+impl Toasts {
+    // Starts the timers of new notifications and drops the expired ones.
+    pub fn update(&mut self) {
+        let now = Instant::now();
+        LOCAL.with(|l| *l.borrow_mut() = Arc::downgrade(&self.inbox));
+        let mut queued = std::mem::take(&mut *lock(&self.inbox.messages));
+        queued.append(&mut lock(&QUEUE));
+        for (level, message) in queued {
+            self.list.push(Toast {
+                level,
+                message,
+                expires: None,
+            });
+        }
+        self.list
+            .retain(|toast| toast.expires.is_none_or(|expires| expires > now));
+    }
+
+    pub fn render(&mut self, screen: Rect, buf: &mut Buffer) {
+        let mut used = 0u16;
+        // Newest first, nearest to the corner.
+        for toast in self.list.iter_mut().rev() {
+            let longest = toast
+                .message
+                .split('\n')
+                .chain([toast.level.title()])
+                .map(|line| line.chars().count() as u16)
+                .max()
+                .unwrap_or(0);
+            let width = longest.saturating_add(2).min(MAX_WIDTH).min(screen.width);
+            let lines = wrap(&toast.message, width.saturating_sub(2)).len();
+            let lines = u16::try_from(lines).unwrap_or(u16::MAX);
+            let height = lines.saturating_add(2);
+            if used.saturating_add(height) > screen.height {
+                break;
+            }
+            // The timer only runs while the toast is actually on screen.
+            toast.expires.get_or_insert(Instant::now() + self.timeout);
+
+            let x = match self.corner {
+                Corner::TopLeft | Corner::BottomLeft => screen.x,
+                Corner::TopRight | Corner::BottomRight => screen.right() - width,
+            };
+            let y = match self.corner {
+                Corner::TopLeft | Corner::TopRight => screen.y + used,
+                Corner::BottomLeft | Corner::BottomRight => screen.bottom() - used - height,
+            };
+            used += height;
+
+            let area = Rect::new(x, y, width, height);
+            let style = Style::default().fg(toast.level.color());
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(toast.level.title())
+                .title_style(style);
+            Clear.render(area, buf);
+            render_lines(&toast.message, block.inner(area), buf, Style::default());
+            block.render(area, buf);
+        }
+    }
+}
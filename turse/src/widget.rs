@@ -0,0 +1,524 @@
+use std::{collections::HashMap, ops::Range};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::{Position, Rect, Size},
+};
+use turse_core::{AttrValue, Event, Node};
+
+use crate::{
+    node_ref::node_ref,
+    state::StateMap,
+    widgets::{self, form},
+};
+
+pub(crate) trait Attrs {
+    fn int(&self, name: &str) -> Option<i64>;
+    fn text(&self, name: &str) -> Option<String>;
+    fn flag(&self, name: &str) -> bool;
+    fn emit(&self, name: &str, event: Event);
+}
+
+impl Attrs for HashMap<String, AttrValue> {
+    fn int(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(|v| v.as_int())
+    }
+
+    fn text(&self, name: &str) -> Option<String> {
+        self.get(name).map(|v| v.to_string())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.get(name).and_then(|v| v.as_bool()).unwrap_or(false)
+    }
+
+    fn emit(&self, name: &str, event: Event) {
+        if let Some(AttrValue::Handler(handler)) = self.get(name) {
+            handler.call(&event);
+        }
+    }
+}
+
+pub(crate) struct El<'a> {
+    pub attrs: &'a HashMap<String, AttrValue>,
+    pub children: &'a [Node],
+}
+
+pub(crate) trait Widget: Sync {
+    // `ctx.key` is the key the element will be rendered at.
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16;
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx);
+
+    fn focusable(&self, _el: &El) -> bool {
+        false
+    }
+
+    fn on_key(&self, _mounted: &Mounted, _key: KeyEvent, _state: &mut StateMap) -> bool {
+        false
+    }
+
+    fn on_scroll(&self, _mounted: &Mounted, _delta: isize, _state: &mut StateMap) -> bool {
+        false
+    }
+
+    // Current value of a form field.
+    fn value(&self, _mounted: &Mounted, _state: &mut StateMap) -> Option<AttrValue> {
+        None
+    }
+
+    // Widget specific methods invoked through a `NodeRef`.
+    fn call(&self, _mounted: &Mounted, _method: &str, _state: &mut StateMap) -> bool {
+        false
+    }
+
+    // `position` is relative to the top left corner of the element on screen.
+    fn on_click(&self, mounted: &Mounted, _position: Position, _state: &mut StateMap) -> bool {
+        mounted.attrs.emit("onclick", Event::Click);
+        mounted.attrs.contains_key("onclick")
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Mounted {
+    pub key: String,
+    pub tag: String,
+    pub attrs: HashMap<String, AttrValue>,
+    // Visible area on screen, clipped by every enclosing scroll container.
+    pub rect: Rect,
+    // Rows within the content of `scroll_parent`, or on screen if there is none.
+    pub content: Range<usize>,
+    pub scroll_parent: Option<String>,
+    pub parent: Option<String>,
+    // The `form` the element is a field of.
+    pub form: Option<String>,
+}
+
+#[derive(Default)]
+pub(crate) struct Mounts {
+    pub list: Vec<Mounted>,
+    pub focusable: Vec<String>,
+    // State kept alive for keys that are not mounted, together with everything below them.
+    pub kept: Vec<String>,
+    // Index in `list` of the topmost modal, which hides everything mounted before it from input.
+    pub trap: Option<usize>,
+    index: HashMap<String, usize>,
+}
+
+impl Mounts {
+    pub fn push(&mut self, mounted: Mounted) {
+        self.index.insert(mounted.key.clone(), self.list.len());
+        self.list.push(mounted);
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Mounted> {
+        self.index.get(key).map(|&i| &self.list[i])
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.index.contains_key(key)
+    }
+
+    // The element itself followed by every element it is nested in.
+    pub fn ancestors<'a>(&'a self, key: &str) -> impl Iterator<Item = &'a Mounted> {
+        std::iter::successors(self.get(key), |m| {
+            m.parent.as_deref().and_then(|parent| self.get(parent))
+        })
+    }
+
+    pub fn keeps(&self, key: &str) -> bool {
+        self.contains(key)
+            || self.kept.iter().any(|kept| {
+                key.strip_prefix(kept.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+    }
+
+    // Innermost elements come last, so walking backwards finds the deepest hit first.
+    pub fn at(&self, x: u16, y: u16) -> impl Iterator<Item = &Mounted> {
+        self.list[self.trap.unwrap_or(0)..]
+            .iter()
+            .rev()
+            .filter(move |m| m.rect.contains(Position::new(x, y)))
+    }
+}
+
+// An element drawn above the rest of the tree once the normal layout is done.
+pub(crate) struct Layer {
+    pub key: String,
+    pub node: Node,
+}
+
+#[derive(Clone, Copy)]
+struct View {
+    dx: i32,
+    dy: i32,
+    clip: Rect,
+}
+
+pub(crate) struct Ctx<'a> {
+    pub state: &'a mut StateMap,
+    pub mounts: &'a mut Mounts,
+    pub focused: Option<&'a str>,
+    pub pressed: Option<&'a str>,
+    pub hovered: &'a [String],
+    pub key: String,
+    pub layers: Vec<Layer>,
+    pub form: Option<String>,
+    view: View,
+    scroll_parent: Option<String>,
+    // Row of the scrolled content at the top of the buffer being rendered to.
+    base: usize,
+}
+
+impl<'a> Ctx<'a> {
+    pub fn new(state: &'a mut StateMap, mounts: &'a mut Mounts, area: Rect) -> Self {
+        Self {
+            state,
+            mounts,
+            focused: None,
+            pressed: None,
+            hovered: &[],
+            key: String::new(),
+            layers: Vec::new(),
+            form: None,
+            view: View {
+                dx: 0,
+                dy: 0,
+                clip: area,
+            },
+            scroll_parent: None,
+            base: 0,
+        }
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused == Some(self.key.as_str())
+    }
+
+    pub fn is_pressed(&self) -> bool {
+        self.pressed == Some(self.key.as_str())
+    }
+
+    pub fn is_hovered(&self) -> bool {
+        self.hovered.contains(&self.key)
+    }
+
+    pub fn to_screen(&self, area: Rect) -> Rect {
+        let x = area.x as i32 + self.view.dx;
+        let y = area.y as i32 + self.view.dy;
+        let clip = self.view.clip;
+        let left = x.max(clip.x as i32);
+        let top = y.max(clip.y as i32);
+        let right = (x + area.width as i32).min(clip.right() as i32);
+        let bottom = (y + area.height as i32).min(clip.bottom() as i32);
+        if right <= left || bottom <= top {
+            return Rect::new(left.max(0) as u16, top.max(0) as u16, 0, 0);
+        }
+        Rect::new(
+            left as u16,
+            top as u16,
+            (right - left) as u16,
+            (bottom - top) as u16,
+        )
+    }
+
+    // Renders the rows of `content` that show in `viewport`, starting at `offset`, offscreen
+    // and copies them in. `render` is given the whole content area and places children with
+    // `render_visible`. Content taller than a `u16` counts is rendered a window at a time, and
+    // `base` is the row of the whole content the window starts at.
+    pub fn scrolled(
+        &mut self,
+        viewport: Rect,
+        buf: &mut Buffer,
+        content: Size,
+        offset: Position,
+        base: usize,
+        render: impl FnOnce(Rect, &mut Buffer, &mut Ctx),
+    ) {
+        let content = Rect::from((Position::ORIGIN, content));
+        let window = Rect::new(0, offset.y, content.width, viewport.height).intersection(content);
+        let mut offscreen = Buffer::empty(window);
+
+        let view = self.view;
+        let scroll_parent = self.scroll_parent.replace(self.key.clone());
+        let outer = std::mem::replace(&mut self.base, base);
+        self.view = View {
+            dx: view.dx + viewport.x as i32 - offset.x as i32,
+            dy: view.dy + viewport.y as i32 - offset.y as i32,
+            clip: self.to_screen(viewport),
+        };
+        render(content, &mut offscreen, self);
+        self.view = view;
+        self.scroll_parent = scroll_parent;
+        self.base = outer;
+
+        for row in 0..viewport.height {
+            let y = offset.y as u32 + row as u32;
+            if y >= window.bottom() as u32 {
+                break;
+            }
+            for col in 0..viewport.width {
+                let x = offset.x as u32 + col as u32;
+                if x >= content.width as u32 {
+                    break;
+                }
+                buf[(viewport.x + col, viewport.y + row)] = offscreen[(x as u16, y as u16)].clone();
+            }
+        }
+    }
+}
+
+// Expands `fragment` children (e.g. from a `Vec` expression) in place.
+pub(crate) fn flatten(children: &[Node]) -> Vec<&Node> {
+    let mut nodes = Vec::new();
+    for child in children {
+        match child {
+            Node::Element { tag, children, .. } if tag == "fragment" => {
+                nodes.extend(flatten(children))
+            }
+            _ => nodes.push(child),
+        }
+    }
+    nodes
+}
+
+pub(crate) fn child_key(parent: &str, index: usize) -> String {
+    format!("{}/{}", parent, index)
+}
+
+pub(crate) fn child_width(node: &Node, available: u16) -> u16 {
+    match node {
+        Node::Element { attrs, .. } => match attrs.int("width") {
+            Some(width) => (width.max(0) as u16).min(available),
+            None => available,
+        },
+        Node::Body(_) => available,
+    }
+}
+
+// Measures `node` as if rendered at `key`, so widgets can size themselves by their state.
+pub(crate) fn measure_node(node: &Node, key: String, width: u16, ctx: &mut Ctx) -> u16 {
+    match node {
+        Node::Body(text) => {
+            u16::try_from(widgets::text::wrap(text, width).len()).unwrap_or(u16::MAX)
+        }
+        Node::Element {
+            tag,
+            attrs,
+            children,
+        } => {
+            let key = match attrs.get("id") {
+                Some(id) => format!("#{}", id),
+                None => key,
+            };
+            let parent = std::mem::replace(&mut ctx.key, key);
+            let height = widgets::get(tag).measure(&El { attrs, children }, width, ctx);
+            ctx.key = parent;
+            height.saturating_add(form::is_validated(attrs) as u16)
+        }
+    }
+}
+
+pub(crate) fn measure_children(children: &[Node], width: u16, ctx: &mut Ctx) -> u16 {
+    children
+        .iter()
+        .enumerate()
+        .fold(0u16, |height, (i, child)| {
+            let key = child_key(&ctx.key, i);
+            height.saturating_add(measure_node(child, key, child_width(child, width), ctx))
+        })
+}
+
+pub(crate) fn render_node(node: &Node, key: String, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+    match node {
+        Node::Body(text) => {
+            widgets::text::render_lines(text, area, buf, ratatui::style::Style::default())
+        }
+        Node::Element {
+            tag,
+            attrs,
+            children,
+        } => {
+            let key = match attrs.get("id") {
+                Some(id) => format!("#{}", id),
+                None => key,
+            };
+            let el = El { attrs, children };
+            let widget = widgets::get(tag);
+            // Validated fields keep their last row for the error message.
+            let (area, error) = match form::is_validated(attrs) && area.height > 0 {
+                true => {
+                    let field = Rect {
+                        height: area.height - 1,
+                        ..area
+                    };
+                    let error = Rect {
+                        y: area.bottom() - 1,
+                        height: 1,
+                        ..area
+                    };
+                    (field, Some(error))
+                }
+                false => (area, None),
+            };
+
+            ctx.mounts.push(Mounted {
+                key: key.clone(),
+                tag: tag.clone(),
+                attrs: attrs.clone(),
+                rect: ctx.to_screen(area),
+                content: rows(ctx, area),
+                scroll_parent: ctx.scroll_parent.clone(),
+                parent: Some(ctx.key.clone()).filter(|parent| !parent.is_empty()),
+                form: ctx.form.clone(),
+            });
+            if widget.focusable(&el) {
+                ctx.mounts.focusable.push(key.clone());
+            }
+            if let Some(node_ref) = node_ref(attrs) {
+                node_ref.bind(&key, ctx.to_screen(area));
+            }
+
+            let parent = std::mem::replace(&mut ctx.key, key);
+            widget.render(&el, area, buf, ctx);
+            if ctx.is_hovered()
+                && let Some(hover) = attrs.text("hover")
+            {
+                buf.set_style(area, widgets::parse_style(&hover));
+            }
+            if let Some(form) = ctx.form.clone()
+                && attrs.contains_key("name")
+            {
+                form::register(&form, error, buf, ctx);
+            }
+            ctx.key = parent;
+        }
+    }
+}
+
+// Stacks children top to bottom. Children that no longer fit are still mounted with an empty
+// area so that their state survives until they are visible again.
+pub(crate) fn render_children(children: &[Node], area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+    let mut y = area.y;
+    for (i, child) in children.iter().enumerate() {
+        let width = child_width(child, area.width);
+        let key = child_key(&ctx.key, i);
+        let height = measure_node(child, key.clone(), width, ctx).min(area.bottom() - y);
+        let rect = Rect::new(area.x, y, width, height);
+        render_visible(child, key, rect, buf, ctx);
+        y += height;
+    }
+}
+
+// Renders `node` as far as it falls on `buf`, which for scrolled content only holds the rows on
+// screen. Nodes off it are mounted with an empty area so that their state survives, and nodes
+// cut off by its edge are drawn whole on a buffer of their own and copied in.
+pub(crate) fn render_visible(
+    node: &Node,
+    key: String,
+    area: Rect,
+    buf: &mut Buffer,
+    ctx: &mut Ctx,
+) {
+    let visible = area.intersection(buf.area);
+    if area.is_empty() || visible == area {
+        render_node(node, key, area, buf, ctx);
+    } else if visible.is_empty() {
+        let index = ctx.mounts.list.len();
+        render_node(node, key, Rect { height: 0, ..area }, buf, ctx);
+        // Still where it would be, so it can be scrolled into view.
+        let content = rows(ctx, area);
+        if let Some(mounted) = ctx.mounts.list.get_mut(index) {
+            mounted.content = content;
+        }
+    } else {
+        let mut part = Buffer::empty(area);
+        copy(buf, &mut part, visible);
+        render_node(node, key, area, &mut part, ctx);
+        copy(&part, buf, visible);
+    }
+}
+
+// Stacks `children` of the given `heights` in `viewport`, scrolled down to row `offset`. Only
+// the children that show are laid out, in a window of the content that starts at the first of
+// them, so the content may be taller than a `u16` counts. The others are mounted without an
+// area so that their state survives.
+pub(crate) fn render_scrolled(
+    children: &[&Node],
+    heights: &[u16],
+    viewport: Rect,
+    offset: usize,
+    buf: &mut Buffer,
+    ctx: &mut Ctx,
+) {
+    let tops: Vec<usize> = heights
+        .iter()
+        .scan(0usize, |top, &height| {
+            let row = *top;
+            *top += height as usize;
+            Some(row)
+        })
+        .collect();
+    let bottom = offset + viewport.height as usize;
+    let shown = |i: usize| tops[i] + heights[i] as usize > offset && tops[i] < bottom;
+    let first = (0..children.len()).find(|&i| shown(i));
+    let base = first.map_or(offset, |i| tops[i]);
+    let end = (0..children.len())
+        .rev()
+        .find(|&i| shown(i))
+        .map_or(base, |i| tops[i] + heights[i] as usize);
+    let window = (end - base).min(u16::MAX as usize) as u16;
+
+    ctx.scrolled(
+        viewport,
+        buf,
+        Size::new(viewport.width, window),
+        Position::new(0, (offset - base) as u16),
+        base,
+        |area, buf, ctx| {
+            for (i, child) in children.iter().enumerate() {
+                let width = child_width(child, area.width);
+                let key = child_key(&ctx.key, i);
+                if shown(i) {
+                    let y = (tops[i] - base).min(u16::MAX as usize) as u16;
+                    let height = heights[i].min(window - y);
+                    render_visible(child, key, Rect::new(area.x, y, width, height), buf, ctx);
+                    continue;
+                }
+                let index = ctx.mounts.list.len();
+                let outer = std::mem::replace(&mut ctx.base, tops[i]);
+                render_node(child, key, Rect::new(area.x, 0, width, 0), buf, ctx);
+                ctx.base = outer;
+                if let Some(mounted) = ctx.mounts.list.get_mut(index) {
+                    mounted.content = tops[i]..tops[i] + heights[i] as usize;
+                }
+            }
+        },
+    );
+}
+
+fn rows(ctx: &Ctx, area: Rect) -> Range<usize> {
+    let top = ctx.base + area.y as usize;
+    top..top + area.height as usize
+}
+
+fn copy(from: &Buffer, to: &mut Buffer, area: Rect) {
+    for position in area.positions() {
+        to[position] = from[position].clone();
+    }
+}
+
+// Layers are drawn in the order they were found, and layers opened from within a layer after
+// that, so nested overlays end up on top.
+pub(crate) fn render_layers(screen: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+    loop {
+        let layers = std::mem::take(&mut ctx.layers);
+        if layers.is_empty() {
+            break;
+        }
+        for layer in &layers {
+            widgets::modal::render_layer(layer, screen, buf, ctx);
+        }
+    }
+}
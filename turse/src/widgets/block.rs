@@ -0,0 +1,130 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Borders, Widget as _},
+};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    state::StateMap,
+    widget::{
+        child_key, child_width, flatten, measure_children, measure_node, render_children,
+        render_scrolled, Attrs, Ctx, El, Mounted, Widget,
+    },
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Overflow {
+    Visible,
+    Hidden,
+    Scroll,
+    Auto,
+}
+
+fn overflow(attrs: &impl Attrs) -> Overflow {
+    match attrs.text("overflow").as_deref() {
+        Some("hidden") => Overflow::Hidden,
+        Some("scroll") => Overflow::Scroll,
+        Some("auto") => Overflow::Auto,
+        _ => Overflow::Visible,
+    }
+}
+
+fn bordered(el: &El) -> bool {
+    el.attrs.flag("border") || el.attrs.contains_key("title")
+}
+
+pub(crate) struct Block;
+
+impl Widget for Block {
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16 {
+        if let Some(height) = el.attrs.int("height") {
+            return height.max(0) as u16;
+        }
+        if bordered(el) {
+            measure_children(el.children, width.saturating_sub(2), ctx).saturating_add(2)
+        } else {
+            measure_children(el.children, width, ctx)
+        }
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let inner = if bordered(el) {
+            let style = if ctx.is_focused() {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let mut block = ratatui::widgets::Block::default()
+                .borders(Borders::ALL)
+                .border_style(style);
+            if let Some(title) = el.attrs.text("title") {
+                block = block.title(title);
+            }
+            let inner = block.inner(area);
+            block.render(area, buf);
+            inner
+        } else {
+            area
+        };
+
+        let overflow = overflow(el.attrs);
+        if overflow == Overflow::Visible {
+            render_children(el.children, inner, buf, ctx);
+            return;
+        }
+
+        // Fragments are laid out flat, so a long `Vec` of children is windowed as well.
+        let children = flatten(el.children);
+        let measure = |width: u16, ctx: &mut Ctx| -> Vec<u16> {
+            let mut heights = Vec::with_capacity(children.len());
+            for (i, child) in children.iter().enumerate() {
+                let key = child_key(&ctx.key, i);
+                heights.push(measure_node(child, key, child_width(child, width), ctx));
+            }
+            heights
+        };
+        let mut heights = measure(inner.width, ctx);
+        let content = |heights: &[u16]| -> usize { heights.iter().map(|&h| h as usize).sum() };
+        let scrollbar = match overflow {
+            Overflow::Scroll => true,
+            Overflow::Auto => content(&heights) > inner.height as usize,
+            _ => false,
+        };
+        let viewport = if scrollbar {
+            heights = measure(inner.width.saturating_sub(1), ctx);
+            Rect {
+                width: inner.width.saturating_sub(1),
+                ..inner
+            }
+        } else {
+            inner
+        };
+
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(viewport.height as usize, content(&heights));
+        let offset = scroll.offset;
+        render_scrolled(&children, &heights, viewport, offset, buf, ctx);
+
+        if scrollbar {
+            render_scrollbar(inner, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        matches!(overflow(el.attrs), Overflow::Scroll | Overflow::Auto)
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        state.get::<ScrollState>(&mounted.key).on_key(key)
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        if !matches!(overflow(&mounted.attrs), Overflow::Scroll | Overflow::Auto) {
+            return false;
+        }
+        state.get::<ScrollState>(&mounted.key).scroll_by(delta)
+    }
+}
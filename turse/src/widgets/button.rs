@@ -0,0 +1,67 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+};
+use turse_core::Event;
+
+use crate::{
+    state::StateMap,
+    widget::{Attrs, Ctx, El, Mounted, Widget},
+    widgets::{form, text::content},
+};
+
+pub(crate) struct Button;
+
+impl Widget for Button {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        1
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        if area.is_empty() {
+            return;
+        }
+        let style = if el.attrs.flag("disabled") {
+            Style::default().fg(Color::DarkGray)
+        } else if ctx.is_pressed() {
+            Style::default()
+                .add_modifier(Modifier::REVERSED | Modifier::BOLD)
+                .fg(Color::Yellow)
+        } else if ctx.is_focused() {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else if ctx.is_hovered() {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let label = format!("[ {} ]", content(el));
+        buf.set_stringn(area.x, area.y, label, area.width as usize, style);
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        !el.attrs.flag("disabled")
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        matches!(key.code, KeyCode::Char(' ') | KeyCode::Enter) && activate(mounted, state)
+    }
+
+    fn on_click(&self, mounted: &Mounted, _position: Position, state: &mut StateMap) -> bool {
+        activate(mounted, state)
+    }
+}
+
+fn activate(mounted: &Mounted, state: &mut StateMap) -> bool {
+    if mounted.attrs.flag("disabled") {
+        return false;
+    }
+    mounted.attrs.emit("onclick", Event::Click);
+    if mounted.attrs.flag("submit")
+        && let Some(form) = &mounted.form
+    {
+        form::submit(form, state);
+    }
+    true
+}
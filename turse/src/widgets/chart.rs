@@ -0,0 +1,204 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Line,
+    widgets::{Axis, Bar, BarGroup, Chart, Dataset, GraphType, Widget as _},
+};
+use turse_core::Node;
+
+use crate::{
+    widget::{flatten, Attrs, Ctx, El, Widget},
+    widgets::color,
+};
+
+const PALETTE: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::Red,
+];
+
+// Ratatui charts take integer values, so fractional data is kept to two decimals.
+const SCALE: f64 = 100.0;
+
+fn data(el: &El) -> Vec<f64> {
+    el.attrs
+        .get("data")
+        .map(|v| v.as_floats())
+        .unwrap_or_default()
+}
+
+fn scaled(value: f64) -> u64 {
+    (value.max(0.0) * SCALE).round() as u64
+}
+
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+fn height(el: &El, default: i64) -> u16 {
+    el.attrs.int("height").unwrap_or(default).max(0) as u16
+}
+
+fn style(el: &El, default: Color) -> Style {
+    let color = el.attrs.text("color").and_then(|name| color(&name));
+    Style::default().fg(color.unwrap_or(default))
+}
+
+pub(crate) struct Sparkline;
+
+impl Widget for Sparkline {
+    fn measure(&self, el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        height(el, 1)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        let values: Vec<u64> = data(el).into_iter().map(scaled).collect();
+        // Only the most recent values are shown when there are more than fit.
+        let start = values.len().saturating_sub(area.width as usize);
+        let mut sparkline = ratatui::widgets::Sparkline::default()
+            .data(&values[start..])
+            .style(style(el, Color::Cyan));
+        if let Some(max) = el.attrs.get("max").and_then(|v| v.as_float()) {
+            sparkline = sparkline.max(scaled(max));
+        }
+        sparkline.render(area, buf);
+    }
+}
+
+pub(crate) struct BarChart;
+
+impl Widget for BarChart {
+    fn measure(&self, el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        height(el, 10)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        let labels = el
+            .attrs
+            .get("labels")
+            .map(|v| v.as_list())
+            .unwrap_or_default();
+        let bars: Vec<Bar> = data(el)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let bar = Bar::default()
+                    .value(scaled(value))
+                    .text_value(format_value(value));
+                match labels.get(i) {
+                    Some(label) => bar.label(Line::from(label.to_string())),
+                    None => bar,
+                }
+            })
+            .collect();
+        let bar_width = el.attrs.int("bar_width").unwrap_or(3).max(1) as u16;
+        let mut chart = ratatui::widgets::BarChart::default()
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .bar_style(style(el, Color::Cyan))
+            .value_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+        if let Some(max) = el.attrs.get("max").and_then(|v| v.as_float()) {
+            chart = chart.max(scaled(max));
+        }
+        chart.render(area, buf);
+    }
+}
+
+struct Series {
+    name: Option<String>,
+    points: Vec<(f64, f64)>,
+    style: Style,
+}
+
+fn series(children: &[Node]) -> Vec<Series> {
+    flatten(children)
+        .into_iter()
+        .filter_map(|node| match node {
+            Node::Element {
+                tag,
+                attrs,
+                children,
+            } if tag == "series" => Some(El { attrs, children }),
+            _ => None,
+        })
+        .enumerate()
+        .map(|(i, el)| Series {
+            name: el.attrs.text("name"),
+            points: data(&el)
+                .into_iter()
+                .enumerate()
+                .map(|(x, y)| (x as f64, y))
+                .collect(),
+            style: style(&el, PALETTE[i % PALETTE.len()]),
+        })
+        .collect()
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> [f64; 2] {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    });
+    if min > max {
+        [0.0, 1.0]
+    } else if min == max {
+        [min - 1.0, max + 1.0]
+    } else {
+        [min, max]
+    }
+}
+
+fn axis<'a>(title: Option<String>, bounds: [f64; 2]) -> Axis<'a> {
+    let axis = Axis::default()
+        .bounds(bounds)
+        .labels([format_value(bounds[0]), format_value(bounds[1])])
+        .style(Style::default().fg(Color::DarkGray));
+    match title {
+        Some(title) => axis.title(title),
+        None => axis,
+    }
+}
+
+pub(crate) struct LineChart;
+
+impl Widget for LineChart {
+    fn measure(&self, el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        height(el, 10)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        let series = series(el.children);
+        let points = || series.iter().flat_map(|s| s.points.iter());
+        let x = bounds(points().map(|&(x, _)| x));
+        let y = bounds(points().map(|&(_, y)| y));
+
+        let datasets = series
+            .iter()
+            .map(|s| {
+                let dataset = Dataset::default()
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(s.style)
+                    .data(&s.points);
+                match &s.name {
+                    Some(name) => dataset.name(name.clone()),
+                    None => dataset,
+                }
+            })
+            .collect();
+        Chart::new(datasets)
+            .x_axis(axis(el.attrs.text("x_label"), x))
+            .y_axis(axis(el.attrs.text("y_label"), y))
+            .hidden_legend_constraints((Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)))
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,164 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::Style,
+};
+use turse_core::{AttrValue, Event};
+
+use crate::{
+    state::StateMap,
+    widget::{Attrs, Ctx, El, Mounted, Widget},
+    widgets::{
+        form, selected_style,
+        text::{content, render_lines, wrap},
+    },
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Checkbox,
+    Radio,
+    Toggle,
+}
+
+#[derive(Default)]
+struct ChoiceState {
+    checked: bool,
+    // The `checked` attribute as of the last render, so the app can still drive the control.
+    bound: Option<bool>,
+    value: String,
+}
+
+#[derive(Default)]
+struct RadioGroup {
+    value: Option<String>,
+}
+
+// Radios share a group by name within their form, or with their siblings outside of one.
+fn group_key(mounted: &Mounted) -> String {
+    let scope = mounted.form.as_ref().or(mounted.parent.as_ref());
+    format!(
+        "{}/group:{}",
+        scope.map_or("", String::as_str),
+        mounted.attrs.text("group").unwrap_or_default()
+    )
+}
+
+fn marker(kind: Kind, checked: bool) -> &'static str {
+    match (kind, checked) {
+        (Kind::Checkbox, true) => "[x] ",
+        (Kind::Checkbox, false) => "[ ] ",
+        (Kind::Radio, true) => "(•) ",
+        (Kind::Radio, false) => "( ) ",
+        (Kind::Toggle, true) => "[on ] ",
+        (Kind::Toggle, false) => "[off] ",
+    }
+}
+
+pub(crate) struct Choice(pub Kind);
+
+impl Widget for Choice {
+    fn measure(&self, el: &El, width: u16, _ctx: &mut Ctx) -> u16 {
+        let marker = marker(self.0, false).chars().count() as u16;
+        let lines = wrap(&content(el), width.saturating_sub(marker)).len();
+        u16::try_from(lines).unwrap_or(u16::MAX)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let label = content(el);
+        let bound = el.attrs.get("checked").and_then(|v| v.as_bool());
+        let focused = ctx.is_focused();
+
+        let state = ctx.state.get::<ChoiceState>(&ctx.key);
+        state.value = el.attrs.text("value").unwrap_or_else(|| label.clone());
+        let changed = bound.is_some() && bound != state.bound;
+        state.bound = bound;
+        if changed {
+            state.checked = bound.unwrap_or(false);
+        }
+        let value = state.value.clone();
+        let mut checked = state.checked;
+
+        if self.0 == Kind::Radio {
+            let group = ctx.mounts.get(&ctx.key).map(group_key).unwrap_or_default();
+            ctx.mounts.kept.push(group.clone());
+            let group = ctx.state.get::<RadioGroup>(&group);
+            if changed && checked {
+                group.value = Some(value.clone());
+            } else if changed && group.value.as_ref() == Some(&value) {
+                group.value = None;
+            }
+            checked = group.value.as_ref() == Some(&value);
+        }
+        if area.is_empty() {
+            return;
+        }
+
+        let marker = marker(self.0, checked);
+        let width = marker.chars().count() as u16;
+        let style = if focused {
+            selected_style(true)
+        } else {
+            Style::default()
+        };
+        buf.set_stringn(area.x, area.y, marker, area.width as usize, style);
+        let body = Rect {
+            x: area.x + width.min(area.width),
+            width: area.width.saturating_sub(width),
+            ..area
+        };
+        render_lines(&label, body, buf, Style::default());
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    // Inside a form Enter submits it rather than toggling.
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        if let (KeyCode::Enter, Some(form)) = (key.code, &mounted.form) {
+            return form::submit(form, state);
+        }
+        matches!(key.code, KeyCode::Char(' ') | KeyCode::Enter) && self.activate(mounted, state)
+    }
+
+    fn on_click(&self, mounted: &Mounted, _position: Position, state: &mut StateMap) -> bool {
+        self.activate(mounted, state)
+    }
+
+    // Radios give the value picked in their group, or nothing yet.
+    fn value(&self, mounted: &Mounted, state: &mut StateMap) -> Option<AttrValue> {
+        Some(match self.0 {
+            Kind::Radio => {
+                let group = state.get::<RadioGroup>(&group_key(mounted));
+                AttrValue::Text(group.value.clone().unwrap_or_default())
+            }
+            _ => AttrValue::Bool(state.get::<ChoiceState>(&mounted.key).checked),
+        })
+    }
+}
+
+impl Choice {
+    fn activate(&self, mounted: &Mounted, state: &mut StateMap) -> bool {
+        let choice = state.get::<ChoiceState>(&mounted.key);
+        if self.0 == Kind::Radio {
+            let value = choice.value.clone();
+            let group = state.get::<RadioGroup>(&group_key(mounted));
+            if group.value.as_ref() == Some(&value) {
+                return false;
+            }
+            group.value = Some(value.clone());
+            mounted
+                .attrs
+                .emit("onchange", Event::Change(AttrValue::Text(value)));
+        } else {
+            choice.checked = !choice.checked;
+            let checked = choice.checked;
+            mounted
+                .attrs
+                .emit("onchange", Event::Change(AttrValue::Bool(checked)));
+        }
+        true
+    }
+}
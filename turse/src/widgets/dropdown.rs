@@ -0,0 +1,129 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::{Color, Style},
+};
+use turse_core::{AttrValue, Event, Node};
+
+use crate::{
+    state::StateMap,
+    widget::{flatten, Attrs, Ctx, El, Mounted, Widget},
+    widgets::{form, selected_style, text::node_text},
+};
+
+#[derive(Default)]
+struct DropdownState {
+    options: Vec<String>,
+    selected: Option<usize>,
+    // The `value` attribute as of the last render.
+    bound: Option<String>,
+}
+
+impl DropdownState {
+    fn select(&mut self, mounted: &Mounted, index: usize) -> bool {
+        if self.options.is_empty() || self.selected == Some(index) {
+            return false;
+        }
+        self.selected = Some(index);
+        let value = self.options[index].clone();
+        mounted
+            .attrs
+            .emit("onchange", Event::Change(AttrValue::Text(value)));
+        true
+    }
+}
+
+fn options(children: &[Node]) -> Vec<String> {
+    flatten(children)
+        .into_iter()
+        .filter(|node| matches!(node, Node::Element { tag, .. } if tag == "item"))
+        .map(node_text)
+        .collect()
+}
+
+// One line showing the chosen `item`; Up and Down pick the previous or next one, Space, Enter
+// and clicks cycle through them. Inside a form Enter submits it instead.
+pub(crate) struct Dropdown;
+
+impl Widget for Dropdown {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        1
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let focused = ctx.is_focused();
+        let bound = el.attrs.text("value");
+        let state = ctx.state.get::<DropdownState>(&ctx.key);
+        state.options = options(el.children);
+        if bound.is_some() && bound != state.bound {
+            state.selected = state.options.iter().position(|o| Some(o) == bound.as_ref());
+            state.bound = bound;
+        }
+        state.selected = state.selected.filter(|&i| i < state.options.len());
+        if area.is_empty() {
+            return;
+        }
+
+        let (label, style) = match state.selected {
+            Some(i) => (state.options[i].clone(), Style::default()),
+            None => (
+                el.attrs.text("placeholder").unwrap_or_default(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        };
+        let style = if focused { selected_style(true) } else { style };
+        let width = area.width as usize;
+        buf.set_stringn(area.x, area.y, label, width.saturating_sub(2), style);
+        if width >= 2 {
+            buf.set_string(area.right() - 1, area.y, "▾", style);
+        }
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        !el.attrs.flag("disabled")
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        if let (KeyCode::Enter, Some(form)) = (key.code, &mounted.form) {
+            return form::submit(form, state);
+        }
+        let dropdown = state.get::<DropdownState>(&mounted.key);
+        let last = dropdown.options.len().saturating_sub(1);
+        let index = match (key.code, dropdown.selected) {
+            (KeyCode::Up, Some(i)) => i.saturating_sub(1),
+            (KeyCode::Up, None) => last,
+            (KeyCode::Down, Some(i)) => (i + 1).min(last),
+            (KeyCode::Down, None) => 0,
+            (KeyCode::Home, _) => 0,
+            (KeyCode::End, _) => last,
+            (KeyCode::Char(' ') | KeyCode::Enter, _) => return self.cycle(mounted, state),
+            _ => return false,
+        };
+        dropdown.select(mounted, index)
+    }
+
+    fn on_click(&self, mounted: &Mounted, _position: Position, state: &mut StateMap) -> bool {
+        self.cycle(mounted, state)
+    }
+
+    fn value(&self, mounted: &Mounted, state: &mut StateMap) -> Option<AttrValue> {
+        let dropdown = state.get::<DropdownState>(&mounted.key);
+        let value = dropdown.selected.map(|i| dropdown.options[i].clone());
+        Some(AttrValue::Text(value.unwrap_or_default()))
+    }
+}
+
+impl Dropdown {
+    fn cycle(&self, mounted: &Mounted, state: &mut StateMap) -> bool {
+        if mounted.attrs.flag("disabled") {
+            return false;
+        }
+        let dropdown = state.get::<DropdownState>(&mounted.key);
+        let next = match dropdown.selected {
+            Some(i) => (i + 1) % dropdown.options.len().max(1),
+            None => 0,
+        };
+        dropdown.select(mounted, next)
+    }
+}
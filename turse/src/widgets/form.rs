@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Style},
+};
+use turse_core::{AttrValue, Event, FormData};
+
+use crate::{
+    command::{self, Command},
+    pattern::Patterns,
+    state::StateMap,
+    widget::{Attrs, Ctx, El, Mounted, Widget},
+    widgets::{self, block::Block},
+};
+
+const VALIDATORS: [&str; 5] = ["required", "pattern", "min", "max", "validate"];
+
+#[derive(Default)]
+struct FormState {
+    // Named fields as of the last render, in order.
+    fields: Vec<Mounted>,
+    attrs: HashMap<String, AttrValue>,
+    // Values the fields were first rendered with, to tell whether they were edited.
+    initial: HashMap<String, String>,
+    focused: HashSet<String>,
+    touched: HashSet<String>,
+    // Errors show for every field once submitting was attempted.
+    submitted: bool,
+    // What `onchange` last reported.
+    last: Option<String>,
+    patterns: Patterns,
+}
+
+pub(crate) fn is_validated(attrs: &HashMap<String, AttrValue>) -> bool {
+    attrs.contains_key("name") && VALIDATORS.iter().any(|v| attrs.contains_key(*v))
+}
+
+fn is_empty(value: &AttrValue) -> bool {
+    match value {
+        AttrValue::Bool(checked) => !checked,
+        value => value.to_string().trim().is_empty(),
+    }
+}
+
+// The first rule the value breaks. Empty fields that are not required are left alone.
+fn validate(
+    attrs: &HashMap<String, AttrValue>,
+    value: &AttrValue,
+    patterns: &mut Patterns,
+) -> Option<String> {
+    if is_empty(value) {
+        return attrs.flag("required").then(|| "Required".to_string());
+    }
+    if let Some(pattern) = attrs.text("pattern") {
+        match patterns.is_match(&pattern, &value.to_string()) {
+            Some(true) => {}
+            Some(false) => return Some("Invalid format".to_string()),
+            None => return Some(format!("Invalid pattern `{}`", pattern)),
+        }
+    }
+    let (min, max) = (attrs.get("min"), attrs.get("max"));
+    if min.is_some() || max.is_some() {
+        let Some(number) = value.as_float() else {
+            return Some("Must be a number".to_string());
+        };
+        if let Some(min) = min.and_then(|min| min.as_float())
+            && number < min
+        {
+            return Some(format!("Must be at least {}", min));
+        }
+        if let Some(max) = max.and_then(|max| max.as_float())
+            && number > max
+        {
+            return Some(format!("Must be at most {}", max));
+        }
+    }
+    match attrs.get("validate") {
+        Some(AttrValue::Validate(validator)) => validator.call(value).err(),
+        _ => None,
+    }
+}
+
+// Values and errors of every field by name, along with the key of each invalid field.
+fn collect(form: &str, state: &mut StateMap) -> (FormData, Vec<String>) {
+    let fields = std::mem::take(&mut state.get::<FormState>(form).fields);
+    let mut data = FormData::default();
+    let mut invalid = Vec::new();
+    for field in &fields {
+        let Some(value) = widgets::get(&field.tag).value(field, state) else {
+            continue;
+        };
+        let name = field.attrs.text("name").unwrap_or_default();
+        // Radios of one group share a name and a value.
+        if data.get(&name).is_none() {
+            data.values.push((name.clone(), value.clone()));
+        }
+        let patterns = &mut state.get::<FormState>(form).patterns;
+        if let Some(message) = validate(&field.attrs, &value, patterns)
+            && data.error(&name).is_none()
+        {
+            data.errors.push((name, message));
+            invalid.push(field.key.clone());
+        }
+    }
+
+    let form = state.get::<FormState>(form);
+    form.fields = fields;
+    for (name, value) in &data.values {
+        if form.initial.get(name) != Some(&value.to_string()) {
+            data.dirty.push(name.clone());
+        }
+        if form.touched.contains(name) {
+            data.touched.push(name.clone());
+        }
+    }
+    (data, invalid)
+}
+
+// Called once a named field inside the form rendered, with `ctx.key` still set to the field.
+pub(crate) fn register(form: &str, error: Option<Rect>, buf: &mut Buffer, ctx: &mut Ctx) {
+    let Some(field) = ctx.mounts.get(&ctx.key).cloned() else {
+        return;
+    };
+    let Some(value) = widgets::get(&field.tag).value(&field, ctx.state) else {
+        return;
+    };
+    let name = field.attrs.text("name").unwrap_or_default();
+    let focused = ctx.is_focused();
+
+    let state = ctx.state.get::<FormState>(form);
+    let message = validate(&field.attrs, &value, &mut state.patterns);
+    state
+        .initial
+        .entry(name.clone())
+        .or_insert_with(|| value.to_string());
+    if focused {
+        state.focused.insert(name.clone());
+    } else if state.focused.contains(&name) {
+        state.touched.insert(name.clone());
+    }
+    let shown = state.submitted || state.touched.contains(&name);
+    state.fields.push(field);
+
+    if let (Some(area), Some(message), true) = (error, message, shown) {
+        let style = Style::default().fg(Color::Red);
+        buf.set_stringn(area.x, area.y, message, area.width as usize, style);
+    }
+}
+
+// Validates the form and fires `onsubmit` if every field passed, otherwise focuses the first
+// field that did not.
+pub(crate) fn submit(form: &str, state: &mut StateMap) -> bool {
+    let (data, invalid) = collect(form, state);
+    let form = state.get::<FormState>(form);
+    form.submitted = true;
+    match invalid.into_iter().next() {
+        Some(key) => command::push(Command::Focus { key }),
+        None => {
+            let attrs = form.attrs.clone();
+            attrs.emit("onsubmit", Event::Form(data));
+        }
+    }
+    true
+}
+
+// Fields are stacked like in a `block`, which also gives forms a border and scrolling.
+pub(crate) struct Form;
+
+impl Widget for Form {
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16 {
+        Block.measure(el, width, ctx)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let form = ctx.state.get::<FormState>(&ctx.key);
+        form.fields.clear();
+        form.attrs = el.attrs.clone();
+
+        let outer = ctx.form.replace(ctx.key.clone());
+        Block.render(el, area, buf, ctx);
+        ctx.form = outer;
+
+        let (data, _) = collect(&ctx.key, ctx.state);
+        let summary = summary(&data);
+        let form = ctx.state.get::<FormState>(&ctx.key);
+        if form
+            .last
+            .replace(summary.clone())
+            .is_some_and(|last| last != summary)
+        {
+            el.attrs.emit("onchange", Event::Form(data));
+        }
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        Block.focusable(el)
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        Block.on_key(mounted, key, state)
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        Block.on_scroll(mounted, delta, state)
+    }
+}
+
+fn summary(data: &FormData) -> String {
+    let values: Vec<String> = data
+        .values
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect();
+    format!(
+        "{:?} {:?} {:?} {:?}",
+        values, data.errors, data.dirty, data.touched
+    )
+}
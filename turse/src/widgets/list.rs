@@ -0,0 +1,244 @@
+use std::{
+    collections::BTreeSet,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::{Position, Rect, Size},
+    style::Style,
+};
+use turse_core::{Event, Node};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    selection::Selection,
+    state::StateMap,
+    widget::{child_key, flatten, measure_node, render_visible, Attrs, Ctx, El, Mounted, Widget},
+    widgets::{selected_style, text::node_text},
+};
+
+const JUMP_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Default)]
+struct ListState {
+    checked: BTreeSet<usize>,
+    labels: Vec<String>,
+    // Top row and height of every item within the list content, as of the last render.
+    rows: Vec<(u16, u16)>,
+    typed: String,
+    typed_at: Option<Instant>,
+}
+
+impl ListState {
+    // Extends the typed prefix and finds the next item starting with it, starting over from
+    // just `c` when the longer prefix matches nothing.
+    fn jump(&mut self, c: char, cursor: Option<usize>) -> Option<usize> {
+        let now = Instant::now();
+        if self
+            .typed_at
+            .is_none_or(|at| now.duration_since(at) > JUMP_TIMEOUT)
+        {
+            self.typed.clear();
+        }
+        self.typed_at = Some(now);
+        self.typed.extend(c.to_lowercase());
+
+        self.find(cursor).or_else(|| {
+            self.typed = c.to_lowercase().collect();
+            self.find(cursor)
+        })
+    }
+
+    fn find(&self, cursor: Option<usize>) -> Option<usize> {
+        // A fresh prefix starts searching after the cursor so repeated letters cycle.
+        let start = match cursor {
+            Some(i) if self.typed.chars().count() == 1 => i + 1,
+            Some(i) => i,
+            None => 0,
+        };
+        let len = self.labels.len();
+        (0..len)
+            .map(|n| (start + n) % len)
+            .find(|&i| self.labels[i].to_lowercase().starts_with(&self.typed))
+    }
+}
+
+fn items(children: &[Node]) -> Vec<&Node> {
+    flatten(children)
+        .into_iter()
+        .filter(|node| matches!(node, Node::Element { tag, .. } if tag == "item"))
+        .collect()
+}
+
+fn marker_width(multiple: bool) -> u16 {
+    if multiple {
+        4
+    } else {
+        0
+    }
+}
+
+fn measure_items(items: &[&Node], width: u16, ctx: &mut Ctx) -> Vec<u16> {
+    let mut heights = Vec::with_capacity(items.len());
+    for (i, item) in items.iter().enumerate() {
+        let key = child_key(&ctx.key, i);
+        heights.push(measure_node(item, key, width, ctx));
+    }
+    heights
+}
+
+pub(crate) struct List;
+
+impl Widget for List {
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16 {
+        if let Some(height) = el.attrs.int("height") {
+            return height.max(0) as u16;
+        }
+        let width = width.saturating_sub(marker_width(el.attrs.flag("multiple")));
+        measure_items(&items(el.children), width, ctx)
+            .into_iter()
+            .fold(0u16, u16::saturating_add)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let items = items(el.children);
+        let multiple = el.attrs.flag("multiple");
+        let marker = marker_width(multiple);
+        let focused = ctx.is_focused();
+
+        let mut heights = measure_items(&items, area.width.saturating_sub(marker), ctx);
+        let mut content: u16 = heights.iter().fold(0, |sum, h| sum.saturating_add(*h));
+        let scrollbar = content > area.height;
+        let viewport = if scrollbar {
+            heights = measure_items(&items, area.width.saturating_sub(1 + marker), ctx);
+            content = heights.iter().fold(0, |sum, h| sum.saturating_add(*h));
+            Rect {
+                width: area.width.saturating_sub(1),
+                ..area
+            }
+        } else {
+            area
+        };
+
+        let selection = ctx.state.get::<Selection>(&ctx.key);
+        selection.clamp(items.len());
+        let cursor = selection.cursor;
+
+        let list = ctx.state.get::<ListState>(&ctx.key);
+        list.checked.retain(|&i| i < items.len());
+        list.labels = items.iter().map(|item| node_text(item)).collect();
+        list.rows = heights
+            .iter()
+            .scan(0u16, |top, &height| {
+                let row = (*top, height);
+                *top = top.saturating_add(height);
+                Some(row)
+            })
+            .collect();
+        let rows = list.rows.clone();
+        let checked = list.checked.clone();
+
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(viewport.height as usize, content as usize);
+        let offset = scroll.offset as u16;
+
+        let size = Size::new(viewport.width, content);
+        ctx.scrolled(
+            viewport,
+            buf,
+            size,
+            Position::new(0, offset),
+            0,
+            |area, buf, ctx| {
+                for (i, item) in items.iter().enumerate() {
+                    let (top, height) = rows[i];
+                    let row = Rect::new(area.x, top, area.width, height);
+                    if multiple && buf.area.contains(row.as_position()) {
+                        let mark = if checked.contains(&i) { "[x] " } else { "[ ] " };
+                        buf.set_stringn(row.x, row.y, mark, row.width as usize, Style::default());
+                    }
+                    let body = Rect {
+                        x: row.x + marker.min(row.width),
+                        width: row.width.saturating_sub(marker),
+                        ..row
+                    };
+                    render_visible(item, child_key(&ctx.key, i), body, buf, ctx);
+                    if cursor == Some(i) {
+                        buf.set_style(row, selected_style(focused));
+                    }
+                }
+            },
+        );
+
+        if scrollbar {
+            render_scrollbar(area, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let multiple = mounted.attrs.flag("multiple");
+        let page = state.get::<ScrollState>(&mounted.key).viewport;
+        let cursor = state.get::<Selection>(&mounted.key).cursor;
+        let list = state.get::<ListState>(&mounted.key);
+        let count = list.labels.len();
+
+        let moved = match key.code {
+            KeyCode::Enter => {
+                if let Some(index) = cursor {
+                    mounted.attrs.emit("onactivate", Event::Activate(index));
+                }
+                return cursor.is_some();
+            }
+            KeyCode::Char(' ') if multiple => {
+                let Some(index) = cursor else {
+                    return false;
+                };
+                if !list.checked.remove(&index) {
+                    list.checked.insert(index);
+                }
+                let checked = list.checked.iter().copied().collect();
+                mounted.attrs.emit("onselect", Event::SelectMany(checked));
+                return true;
+            }
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                match list.jump(c, cursor) {
+                    Some(index) => state.get::<Selection>(&mounted.key).select(index),
+                    None => return false,
+                }
+            }
+            _ => state
+                .get::<Selection>(&mounted.key)
+                .on_key(key, count, page),
+        };
+        if !moved {
+            return false;
+        }
+
+        let Some(index) = state.get::<Selection>(&mounted.key).cursor else {
+            return false;
+        };
+        if let Some(&(top, height)) = state.get::<ListState>(&mounted.key).rows.get(index) {
+            state
+                .get::<ScrollState>(&mounted.key)
+                .reveal(top as usize, height as usize);
+        }
+        if !multiple {
+            mounted.attrs.emit("onselect", Event::Select(index));
+        }
+        true
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        state.get::<ScrollState>(&mounted.key).scroll_by(delta)
+    }
+}
@@ -0,0 +1,94 @@
+pub(crate) mod block;
+pub(crate) mod button;
+pub(crate) mod chart;
+pub(crate) mod choice;
+pub(crate) mod dropdown;
+pub(crate) mod form;
+pub(crate) mod list;
+pub(crate) mod modal;
+pub(crate) mod progress;
+pub(crate) mod table;
+pub(crate) mod tabs;
+pub(crate) mod text;
+pub(crate) mod textarea;
+pub(crate) mod tree;
+pub(crate) mod virtual_list;
+
+use std::str::FromStr;
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::widget::Widget;
+
+pub(crate) fn get(tag: &str) -> &'static dyn Widget {
+    match tag {
+        "text" => &text::Text,
+        "barchart" => &chart::BarChart,
+        "button" => &button::Button,
+        "checkbox" => &choice::Choice(choice::Kind::Checkbox),
+        "dropdown" => &dropdown::Dropdown,
+        "form" => &form::Form,
+        "radio" => &choice::Choice(choice::Kind::Radio),
+        "toggle" => &choice::Choice(choice::Kind::Toggle),
+        "gauge" | "meter" => &progress::Meter,
+        "input" => &textarea::Input,
+        "linechart" => &chart::LineChart,
+        "list" => &list::List,
+        "modal" => &modal::Overlay(modal::Kind::Modal),
+        "overlay" => &modal::Overlay(modal::Kind::Overlay),
+        "progress" => &progress::Progress,
+        "sparkline" => &chart::Sparkline,
+        "tab" => &tabs::Tab,
+        "table" => &table::Table,
+        "tabs" => &tabs::Tabs,
+        "textarea" => &textarea::Textarea,
+        "tree" => &tree::Tree,
+        "virtual_list" => &virtual_list::VirtualList,
+        _ => &block::Block,
+    }
+}
+
+pub(crate) fn selected_style(focused: bool) -> Style {
+    if focused {
+        Style::default().add_modifier(Modifier::REVERSED)
+    } else {
+        Style::default().bg(Color::DarkGray)
+    }
+}
+
+// Parses space separated modifiers and colors, e.g. `bold yellow on blue`.
+pub(crate) fn parse_style(text: &str) -> Style {
+    let mut style = Style::default();
+    let mut background = false;
+    for word in text.split_whitespace() {
+        let modifier = match word {
+            "bold" => Modifier::BOLD,
+            "dim" => Modifier::DIM,
+            "italic" => Modifier::ITALIC,
+            "underlined" => Modifier::UNDERLINED,
+            "reversed" => Modifier::REVERSED,
+            "crossed_out" => Modifier::CROSSED_OUT,
+            "on" => {
+                background = true;
+                continue;
+            }
+            _ => {
+                if let Some(color) = color(word) {
+                    style = if background {
+                        style.bg(color)
+                    } else {
+                        style.fg(color)
+                    };
+                }
+                continue;
+            }
+        };
+        style = style.add_modifier(modifier);
+    }
+    style
+}
+
+// Accepts ratatui color names such as `red`, `lightblue`, `#ff8800` or an indexed `208`.
+pub(crate) fn color(name: &str) -> Option<Color> {
+    Color::from_str(name.trim()).ok()
+}
@@ -0,0 +1,165 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Widget as _},
+};
+use turse_core::{Event, Node};
+
+use crate::{
+    node_ref::node_ref,
+    state::StateMap,
+    widget::{measure_children, render_children, Attrs, Ctx, El, Layer, Mounted, Widget},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Modal,
+    Overlay,
+}
+
+#[derive(Default)]
+struct ModalState {
+    dismissed: bool,
+    // The `open` attribute as of the last render; reopening clears a previous dismissal.
+    bound: Option<bool>,
+}
+
+fn tag(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Modal => "modal",
+        Kind::Overlay => "overlay",
+    }
+}
+
+fn bordered(tag: &str, attrs: &impl Attrs) -> bool {
+    tag == "modal" || attrs.flag("border") || attrs.text("title").is_some()
+}
+
+fn align(screen: Rect, width: u16, height: u16, align: Option<&str>) -> Rect {
+    let x = screen.x + (screen.width - width) / 2;
+    let y = match align {
+        Some("top") => screen.y,
+        Some("bottom") => screen.bottom() - height,
+        _ => screen.y + (screen.height - height) / 2,
+    };
+    Rect::new(x, y, width, height)
+}
+
+// Overlays take no space in the flow; they are queued and drawn by `render_layer` afterwards.
+pub(crate) struct Overlay(pub Kind);
+
+impl Widget for Overlay {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        0
+    }
+
+    fn render(&self, el: &El, _area: Rect, _buf: &mut Buffer, ctx: &mut Ctx) {
+        let bound = el.attrs.get("open").and_then(|v| v.as_bool());
+        let state = ctx.state.get::<ModalState>(&ctx.key);
+        if bound.is_some() && bound != state.bound {
+            state.dismissed = false;
+        }
+        state.bound = bound;
+        if bound == Some(false) || state.dismissed {
+            return;
+        }
+
+        ctx.layers.push(Layer {
+            key: ctx.key.clone(),
+            node: Node::Element {
+                tag: tag(self.0).to_string(),
+                attrs: el.attrs.clone(),
+                children: el.children.to_vec(),
+            },
+        });
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        if self.0 != Kind::Modal || key.code != KeyCode::Esc {
+            return false;
+        }
+        state.get::<ModalState>(&mounted.key).dismissed = true;
+        mounted.attrs.emit("ondismiss", Event::Dismiss);
+        true
+    }
+}
+
+pub(crate) fn render_layer(layer: &Layer, screen: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+    let Node::Element {
+        tag,
+        attrs,
+        children,
+    } = &layer.node
+    else {
+        return;
+    };
+    let modal = tag == "modal";
+    let frame = if bordered(tag, attrs) { 2 } else { 0 };
+
+    let width = match attrs.int("width") {
+        Some(width) => width.max(0) as u16,
+        None => (screen.width as u32 * 2 / 3) as u16,
+    }
+    .min(screen.width);
+    let height = match attrs.int("height") {
+        Some(height) => height.max(0) as u16,
+        None => {
+            let parent = std::mem::replace(&mut ctx.key, layer.key.clone());
+            let inner = measure_children(children, width.saturating_sub(frame), ctx);
+            ctx.key = parent;
+            inner.saturating_add(frame)
+        }
+    }
+    .min(screen.height);
+    let area = align(screen, width, height, attrs.text("align").as_deref());
+
+    let backdrop = attrs
+        .get("backdrop")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    if modal && backdrop {
+        buf.set_style(screen, Style::default().fg(Color::DarkGray));
+    }
+    Clear.render(area, buf);
+
+    if modal {
+        ctx.mounts.trap = Some(ctx.mounts.list.len());
+    }
+    let parent = ctx.mounts.get(&layer.key).and_then(|m| m.parent.clone());
+    if let Some(node_ref) = node_ref(attrs) {
+        node_ref.bind(&layer.key, area);
+    }
+    ctx.mounts.push(Mounted {
+        key: layer.key.clone(),
+        tag: tag.clone(),
+        attrs: attrs.clone(),
+        rect: area,
+        content: area.y as usize..area.bottom() as usize,
+        scroll_parent: None,
+        parent,
+        form: None,
+    });
+
+    let inner = if frame > 0 {
+        let mut block = Block::default().borders(Borders::ALL);
+        if let Some(title) = attrs.text("title") {
+            block = block.title(title);
+        }
+        let inner = block.inner(area);
+        block.render(area, buf);
+        inner
+    } else {
+        area
+    };
+
+    // Only what is inside the topmost modal can take focus while it is open.
+    let focusable = ctx.mounts.focusable.len();
+    let parent = std::mem::replace(&mut ctx.key, layer.key.clone());
+    render_children(children, inner, buf, ctx);
+    ctx.key = parent;
+    if modal {
+        ctx.mounts.focusable.drain(..focusable);
+    }
+}
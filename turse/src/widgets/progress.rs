@@ -0,0 +1,125 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::{Gauge, LineGauge, Widget as _},
+};
+
+use crate::{
+    widget::{Attrs, Ctx, El, Widget},
+    widgets::text::node_text,
+};
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+fn ratio(el: &El) -> f64 {
+    let min = el
+        .attrs
+        .get("min")
+        .and_then(|v| v.as_float())
+        .unwrap_or(0.0);
+    let max = el
+        .attrs
+        .get("max")
+        .and_then(|v| v.as_float())
+        .unwrap_or(100.0);
+    let value = el
+        .attrs
+        .get("value")
+        .and_then(|v| v.as_float())
+        .unwrap_or(min);
+    if max <= min {
+        return 0.0;
+    }
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+fn text_label(el: &El) -> Option<String> {
+    let body: String = el.children.iter().map(node_text).collect();
+    if !body.is_empty() {
+        return Some(body);
+    }
+    el.attrs
+        .text("label")
+        .filter(|label| label != "true" && label != "false")
+}
+
+fn label(el: &El, ratio: f64) -> Option<String> {
+    if el.attrs.text("label").as_deref() == Some("false") {
+        return None;
+    }
+    text_label(el).or_else(|| Some(format!("{}%", (ratio * 100.0).round())))
+}
+
+fn threshold_color(el: &El) -> Color {
+    let value = el
+        .attrs
+        .get("value")
+        .and_then(|v| v.as_float())
+        .unwrap_or(0.0);
+    let at_least = |name: &str| {
+        el.attrs
+            .get(name)
+            .and_then(|v| v.as_float())
+            .is_some_and(|threshold| value >= threshold)
+    };
+    if at_least("critical") {
+        Color::Red
+    } else if at_least("warn") {
+        Color::Yellow
+    } else {
+        Color::Green
+    }
+}
+
+pub(crate) struct Progress;
+
+impl Widget for Progress {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        1
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        if el.attrs.flag("indeterminate") {
+            let millis = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            let frame = SPINNER[(millis / 100) as usize % SPINNER.len()];
+            let text = match text_label(el) {
+                Some(label) => format!("{} {}", frame, label),
+                None => frame.to_string(),
+            };
+            buf.set_stringn(area.x, area.y, text, area.width as usize, Style::default());
+            return;
+        }
+
+        let ratio = ratio(el);
+        let gauge = Gauge::default()
+            .ratio(ratio)
+            .label(label(el, ratio).unwrap_or_default())
+            .gauge_style(Style::default().fg(Color::Cyan))
+            .use_unicode(true);
+        gauge.render(area, buf);
+    }
+}
+
+pub(crate) struct Meter;
+
+impl Widget for Meter {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        1
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        let ratio = ratio(el);
+        LineGauge::default()
+            .ratio(ratio)
+            .label(label(el, ratio).unwrap_or_default())
+            .filled_style(Style::default().fg(threshold_color(el)))
+            .unfilled_style(Style::default().fg(Color::DarkGray))
+            .render(area, buf);
+    }
+}
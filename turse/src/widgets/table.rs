@@ -0,0 +1,297 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect, Size},
+    style::{Color, Modifier, Style},
+};
+use turse_core::{AttrValue, Event, Node};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    selection::Selection,
+    state::StateMap,
+    widget::{child_key, flatten, render_visible, Attrs, Ctx, El, Mounted, Widget},
+    widgets::{selected_style, text::node_text},
+};
+
+const HSCROLL_STEP: isize = 4;
+
+#[derive(Default)]
+struct TableState {
+    sort: Option<(usize, bool)>,
+    // Display position to row index, and row index to row key, as of the last render.
+    order: Vec<usize>,
+    keys: Vec<String>,
+    // The `key` of the selected row, so the selection stays on it when rows come and go.
+    selected: Option<String>,
+    sortable: Vec<bool>,
+    reveal: bool,
+    hscroll: ScrollState,
+}
+
+struct Column<'a> {
+    attrs: &'a HashMap<String, AttrValue>,
+    title: String,
+}
+
+struct Row<'a> {
+    index: usize,
+    // The `key` attribute, or the index for rows without one.
+    key: String,
+    cells: &'a [Node],
+}
+
+fn parts(children: &[Node]) -> (Vec<Column<'_>>, Vec<Row<'_>>) {
+    let mut columns = Vec::new();
+    let mut rows = Vec::new();
+    for child in flatten(children) {
+        let Node::Element {
+            tag,
+            attrs,
+            children,
+        } = child
+        else {
+            continue;
+        };
+        match tag.as_str() {
+            "column" => columns.push(Column {
+                attrs,
+                title: children.iter().map(node_text).collect(),
+            }),
+            "row" => rows.push(Row {
+                index: rows.len(),
+                key: attrs
+                    .get("key")
+                    .map_or_else(|| rows.len().to_string(), ToString::to_string),
+                cells: children,
+            }),
+            _ => {}
+        }
+    }
+    (columns, rows)
+}
+
+fn cell_text(row: &Row, column: usize) -> String {
+    row.cells.get(column).map(node_text).unwrap_or_default()
+}
+
+fn compare(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+fn sort_marker(sort: Option<(usize, bool)>, column: usize) -> &'static str {
+    match sort {
+        Some((c, false)) if c == column => " ▲",
+        Some((c, true)) if c == column => " ▼",
+        _ => "",
+    }
+}
+
+fn widths(columns: &[Column], rows: &[Row], sort: Option<(usize, bool)>) -> Vec<u16> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(j, column)| {
+            if let Some(width) = column.attrs.int("width") {
+                return width.max(0) as u16;
+            }
+            let header = column.title.chars().count() + sort_marker(sort, j).chars().count();
+            let content = rows
+                .iter()
+                .map(|row| cell_text(row, j).chars().count())
+                .fold(header, usize::max) as i64;
+            let min = column.attrs.int("min_width").unwrap_or(0);
+            let max = column.attrs.int("max_width").unwrap_or(i64::MAX);
+            content.min(max).max(min).clamp(0, u16::MAX as i64) as u16
+        })
+        .collect()
+}
+
+pub(crate) struct Table;
+
+impl Widget for Table {
+    fn measure(&self, el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        match el.attrs.int("height") {
+            Some(height) => height.max(0) as u16,
+            None => {
+                let (_, rows) = parts(el.children);
+                (rows.len() + 1).min(u16::MAX as usize) as u16
+            }
+        }
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        if area.is_empty() {
+            return;
+        }
+        let (columns, rows) = parts(el.children);
+        let focused = ctx.is_focused();
+        let table = ctx.state.get::<TableState>(&ctx.key);
+        let sort = table.sort;
+
+        let mut order: Vec<usize> = rows.iter().map(|row| row.index).collect();
+        if let Some((column, descending)) = sort {
+            let keys: Vec<String> = rows.iter().map(|row| cell_text(row, column)).collect();
+            order.sort_by(|&a, &b| {
+                let ordering = compare(&keys[a], &keys[b]);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        let selected = table
+            .selected
+            .as_ref()
+            .and_then(|selected| rows.iter().find(|row| row.key == *selected));
+        table.selected = selected.map(|row| row.key.clone());
+        let cursor = selected.and_then(|row| order.iter().position(|&i| i == row.index));
+
+        let widths = widths(&columns, &rows, sort);
+        let total = widths
+            .iter()
+            .fold(widths.len().saturating_sub(1) as u16, |total, w| {
+                total.saturating_add(*w)
+            });
+
+        let body = Rect {
+            y: area.y + 1,
+            height: area.height - 1,
+            ..area
+        };
+        let scrollbar = rows.len() > body.height as usize;
+        let viewport = Rect {
+            width: body.width.saturating_sub(scrollbar as u16),
+            ..body
+        };
+
+        table
+            .hscroll
+            .resize(viewport.width as usize, total as usize);
+        let hscroll = table.hscroll.offset as u16;
+        table.order = order;
+        table.keys = rows.iter().map(|row| row.key.clone()).collect();
+        table.sortable = columns
+            .iter()
+            .map(|column| column.attrs.get("sortable").and_then(|v| v.as_bool()) != Some(false))
+            .collect();
+        let order = table.order.clone();
+        let reveal = std::mem::take(&mut table.reveal);
+
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(viewport.height as usize, rows.len());
+        if let (true, Some(cursor)) = (reveal, cursor) {
+            scroll.reveal(cursor, 1);
+        }
+        let offset = scroll.offset;
+
+        let header = Rect {
+            height: 1,
+            width: viewport.width,
+            ..area
+        };
+        let header_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        ctx.scrolled(
+            header,
+            buf,
+            Size::new(total, 1),
+            Position::new(hscroll, 0),
+            0,
+            |area, buf, _ctx| {
+                let mut x = area.x;
+                for (j, column) in columns.iter().enumerate() {
+                    let title = format!("{}{}", column.title, sort_marker(sort, j));
+                    buf.set_stringn(x, area.y, title, widths[j] as usize, header_style);
+                    x = x.saturating_add(widths[j]).saturating_add(1);
+                }
+            },
+        );
+
+        let striped = el.attrs.flag("striped");
+        // Only the rows on screen are laid out, starting at `offset`, so tables of any length
+        // fit in a `u16` area.
+        let shown = (rows.len() - offset).min(viewport.height as usize);
+        ctx.scrolled(
+            viewport,
+            buf,
+            Size::new(total, shown as u16),
+            Position::new(hscroll, 0),
+            offset,
+            |area, buf, ctx| {
+                let visible = order.iter().enumerate().skip(offset).take(shown);
+                for (line_y, (y, &index)) in (area.y..).zip(visible) {
+                    let line = Rect::new(area.x, line_y, area.width, 1);
+                    if striped && y % 2 == 1 {
+                        buf.set_style(line, Style::default().bg(Color::Indexed(236)));
+                    }
+                    let row_key = child_key(&ctx.key, index);
+                    let mut x = area.x;
+                    for (j, cell) in rows[index].cells.iter().enumerate().take(widths.len()) {
+                        let rect = Rect::new(x, line_y, widths[j], 1);
+                        render_visible(cell, child_key(&row_key, j), rect, buf, ctx);
+                        x = x.saturating_add(widths[j]).saturating_add(1);
+                    }
+                    if cursor == Some(y) {
+                        buf.set_style(line, selected_style(focused));
+                    }
+                }
+            },
+        );
+
+        if scrollbar {
+            render_scrollbar(body, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let page = state.get::<ScrollState>(&mounted.key).viewport;
+        let table = state.get::<TableState>(&mounted.key);
+        match key.code {
+            KeyCode::Left => table.hscroll.scroll_by(-HSCROLL_STEP),
+            KeyCode::Right => table.hscroll.scroll_by(HSCROLL_STEP),
+            KeyCode::Char(c @ '1'..='9') => {
+                let column = c as usize - '1' as usize;
+                if !table.sortable.get(column).copied().unwrap_or(false) {
+                    return false;
+                }
+                table.sort = match table.sort {
+                    Some((c, descending)) if c == column => Some((column, !descending)),
+                    _ => Some((column, false)),
+                };
+                table.reveal = true;
+                true
+            }
+            _ => {
+                let mut selection = Selection {
+                    cursor: table.selected.as_ref().and_then(|selected| {
+                        table.order.iter().position(|&i| table.keys[i] == *selected)
+                    }),
+                };
+                if !selection.on_key(key, table.order.len(), page) {
+                    return false;
+                }
+                let index = selection.cursor.map(|cursor| table.order[cursor]);
+                table.selected = index.map(|index| table.keys[index].clone());
+                table.reveal = true;
+                if let Some(index) = index {
+                    mounted.attrs.emit("onselect", Event::Select(index));
+                }
+                true
+            }
+        }
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        state.get::<ScrollState>(&mounted.key).scroll_by(delta)
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::{Color, Style},
+};
+use turse_core::{Event, Node};
+
+use crate::{
+    state::StateMap,
+    widget::{
+        child_key, flatten, measure_children, measure_node, render_children, render_node, Attrs,
+        Ctx, El, Mounted, Widget,
+    },
+    widgets::selected_style,
+};
+
+const SEPARATOR: &str = "│";
+
+#[derive(Default)]
+struct TabsState {
+    active: usize,
+    // The `active` attribute as of the last render, so the app can still switch tabs.
+    bound: Option<usize>,
+    count: usize,
+    // Columns covered by each title in the header, relative to the element.
+    titles: Vec<(u16, u16)>,
+    // Keys beneath each panel that its own key is no prefix of, such as those of elements with
+    // an `id`, as of the last time the panel was shown.
+    detached: HashMap<String, Vec<String>>,
+}
+
+fn panels(children: &[Node]) -> Vec<&Node> {
+    flatten(children)
+        .into_iter()
+        .filter(|node| matches!(node, Node::Element { tag, .. } if tag == "tab"))
+        .collect()
+}
+
+fn title(node: &Node, index: usize) -> String {
+    match node {
+        Node::Element { attrs, .. } => attrs
+            .text("title")
+            .unwrap_or_else(|| format!("Tab {}", index + 1)),
+        Node::Body(_) => String::new(),
+    }
+}
+
+// Matches the key `render_node` will give the panel, so hidden panels keep the right state.
+fn panel_key(node: &Node, parent: &str, index: usize) -> String {
+    match node {
+        Node::Element { attrs, .. } if attrs.contains_key("id") => {
+            format!("#{}", attrs.text("id").unwrap_or_default())
+        }
+        _ => child_key(parent, index),
+    }
+}
+
+pub(crate) struct Tabs;
+
+impl Widget for Tabs {
+    // Sized for the tallest panel so that switching tabs does not move the rest of the layout.
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16 {
+        if let Some(height) = el.attrs.int("height") {
+            return height.max(0) as u16;
+        }
+        let mut panel = 0;
+        for (i, node) in panels(el.children).into_iter().enumerate() {
+            let key = child_key(&ctx.key, i);
+            panel = panel.max(measure_node(node, key, width, ctx));
+        }
+        panel.saturating_add(1)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let panels = panels(el.children);
+        let bound = el.attrs.int("active").map(|active| active.max(0) as usize);
+        let focused = ctx.is_focused();
+
+        let state = ctx.state.get::<TabsState>(&ctx.key);
+        if bound.is_some() && bound != state.bound {
+            state.active = bound.unwrap_or(0);
+        }
+        state.bound = bound;
+        state.count = panels.len();
+        state.active = state.active.min(panels.len().saturating_sub(1));
+        let active = state.active;
+
+        state.titles.clear();
+        let mut x = area.x;
+        for (i, panel) in panels.iter().enumerate() {
+            if x >= area.right() {
+                break;
+            }
+            if i > 0 {
+                let style = Style::default().fg(Color::DarkGray);
+                buf.set_stringn(x, area.y, SEPARATOR, (area.right() - x) as usize, style);
+                x += 1;
+            }
+            let style = if i == active {
+                selected_style(focused)
+            } else {
+                Style::default()
+            };
+            let label = format!(" {} ", title(panel, i));
+            let (end, _) = buf.set_stringn(x, area.y, label, (area.right() - x) as usize, style);
+            state.titles.push((x - area.x, end - area.x));
+            x = end;
+        }
+
+        // Hidden panels are not rendered, but their state is kept for when they are shown again.
+        let mut detached = std::mem::take(&mut state.detached);
+        for (i, panel) in panels.iter().enumerate().filter(|&(i, _)| i != active) {
+            let key = panel_key(panel, &ctx.key, i);
+            if let Some(keys) = detached.get(&key) {
+                ctx.mounts.kept.extend(keys.iter().cloned());
+            }
+            ctx.mounts.kept.push(key);
+        }
+        if let Some(panel) = panels.get(active) {
+            let body = Rect {
+                y: area.y + 1.min(area.height),
+                height: area.height.saturating_sub(1),
+                ..area
+            };
+            let (mounted, kept) = (ctx.mounts.list.len(), ctx.mounts.kept.len());
+            let key = panel_key(panel, &ctx.key, active);
+            render_node(panel, child_key(&ctx.key, active), body, buf, ctx);
+            let beneath = ctx.mounts.list[mounted..].iter().map(|m| &m.key);
+            let keys = beneath
+                .chain(&ctx.mounts.kept[kept..])
+                .filter(|k| !k.starts_with(&key))
+                .cloned()
+                .collect();
+            detached.insert(key, keys);
+        }
+        ctx.state.get::<TabsState>(&ctx.key).detached = detached;
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let tabs = state.get::<TabsState>(&mounted.key);
+        if tabs.count == 0 {
+            return false;
+        }
+        let last = tabs.count - 1;
+        let active = match key.code {
+            KeyCode::Left => tabs.active.saturating_sub(1),
+            KeyCode::Right => (tabs.active + 1).min(last),
+            KeyCode::Home => 0,
+            KeyCode::End => last,
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = c as usize - '1' as usize;
+                if index > last {
+                    return false;
+                }
+                index
+            }
+            _ => return false,
+        };
+        activate(mounted, tabs, active)
+    }
+
+    fn on_click(&self, mounted: &Mounted, position: Position, state: &mut StateMap) -> bool {
+        let tabs = state.get::<TabsState>(&mounted.key);
+        let clicked = tabs
+            .titles
+            .iter()
+            .position(|&(start, end)| (start..end).contains(&position.x));
+        match clicked {
+            Some(index) if position.y == 0 => activate(mounted, tabs, index),
+            _ => false,
+        }
+    }
+}
+
+fn activate(mounted: &Mounted, tabs: &mut TabsState, index: usize) -> bool {
+    if index == tabs.active {
+        return false;
+    }
+    tabs.active = index;
+    mounted.attrs.emit("onselect", Event::Select(index));
+    true
+}
+
+pub(crate) struct Tab;
+
+impl Widget for Tab {
+    fn measure(&self, el: &El, width: u16, ctx: &mut Ctx) -> u16 {
+        measure_children(el.children, width, ctx)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        render_children(el.children, area, buf, ctx);
+    }
+}
@@ -0,0 +1,61 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style};
+use turse_core::Node;
+
+use crate::widget::{Attrs, Ctx, El, Widget};
+
+pub(crate) fn wrap(text: &str, width: u16) -> Vec<String> {
+    let width = width.max(1) as usize;
+    let mut lines = Vec::new();
+    for line in text.split('\n') {
+        let chars: Vec<char> = line.chars().collect();
+        if chars.is_empty() {
+            lines.push(String::new());
+            continue;
+        }
+        for chunk in chars.chunks(width) {
+            lines.push(chunk.iter().collect());
+        }
+    }
+    lines
+}
+
+pub(crate) fn render_lines(text: &str, area: Rect, buf: &mut Buffer, style: Style) {
+    for (i, line) in wrap(text, area.width).iter().enumerate() {
+        if i as u16 >= area.height {
+            break;
+        }
+        buf.set_stringn(area.x, area.y + i as u16, line, area.width as usize, style);
+    }
+}
+
+pub(crate) fn node_text(node: &Node) -> String {
+    match node {
+        Node::Body(text) => text.clone(),
+        Node::Element {
+            attrs, children, ..
+        } => content(&El { attrs, children }),
+    }
+}
+
+pub(crate) fn content(el: &El) -> String {
+    let body: String = el.children.iter().map(node_text).collect();
+    match el.attrs.text("value") {
+        Some(value) if body.is_empty() => value,
+        _ => body,
+    }
+}
+
+pub(crate) struct Text;
+
+impl Widget for Text {
+    fn measure(&self, el: &El, width: u16, _ctx: &mut Ctx) -> u16 {
+        match el.attrs.int("height") {
+            Some(height) => height.max(0) as u16,
+            None => u16::try_from(wrap(&content(el), width).len()).unwrap_or(u16::MAX),
+        }
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, _ctx: &mut Ctx) {
+        render_lines(&content(el), area, buf, Style::default());
+    }
+}
@@ -0,0 +1,509 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+    layout::Rect,
+    style::{Color, Modifier, Style},
+};
+use turse_core::{AttrValue, Event};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    state::StateMap,
+    widget::{Attrs, Ctx, El, Mounted, Widget},
+    widgets::{form, selected_style, text::wrap},
+};
+
+const HISTORY: usize = 100;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Edit {
+    Insert,
+    Delete,
+    Other,
+}
+
+#[derive(Clone)]
+struct Snapshot {
+    lines: Vec<Vec<char>>,
+    cursor: (usize, usize),
+}
+
+struct TextareaState {
+    lines: Vec<Vec<char>>,
+    // Line and column of the cursor, in chars.
+    cursor: (usize, usize),
+    bound: Option<String>,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+    // Wrap width as of the last render, used to move between visual rows.
+    width: usize,
+    reveal: bool,
+    // Everything is selected, so the next edit replaces the whole text.
+    selected: bool,
+}
+
+impl Default for TextareaState {
+    fn default() -> Self {
+        Self {
+            lines: vec![Vec::new()],
+            cursor: (0, 0),
+            bound: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+            width: 1,
+            reveal: false,
+            selected: false,
+        }
+    }
+}
+
+impl TextareaState {
+    fn text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn set_text(&mut self, text: &str) {
+        self.lines = text
+            .split('\n')
+            .map(|line| line.chars().collect())
+            .collect();
+        let last = self.lines.len() - 1;
+        self.cursor = (last, self.lines[last].len());
+    }
+
+    fn bind(&mut self, bound: Option<String>) {
+        if bound.is_some() && bound != self.bound {
+            // Values echoed back from `onchange` must not move the cursor.
+            if bound.as_deref() != Some(self.text().as_str()) {
+                self.set_text(bound.as_deref().unwrap_or_default());
+            }
+            self.bound = bound;
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.lines = snapshot.lines;
+        self.cursor = snapshot.cursor;
+    }
+
+    // Records an undo step, merging runs of the same kind of edit into one step.
+    fn record(&mut self, edit: Edit, boundary: bool) {
+        if self.last_edit != Some(edit) || boundary || edit == Edit::Other {
+            self.undo.push(self.snapshot());
+            if self.undo.len() > HISTORY {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last_edit = Some(edit);
+    }
+
+    fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop() else {
+            return false;
+        };
+        self.redo.push(self.snapshot());
+        self.restore(snapshot);
+        self.last_edit = None;
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push(self.snapshot());
+        self.restore(snapshot);
+        self.last_edit = None;
+        true
+    }
+
+    fn insert(&mut self, c: char) {
+        self.record(Edit::Insert, c.is_whitespace());
+        let (row, col) = self.cursor;
+        if c == '\n' {
+            let rest = self.lines[row].split_off(col);
+            self.lines.insert(row + 1, rest);
+            self.cursor = (row + 1, 0);
+        } else {
+            self.lines[row].insert(col, c);
+            self.cursor = (row, col + 1);
+        }
+    }
+
+    fn clear(&mut self) -> bool {
+        self.selected = false;
+        if self.lines.len() == 1 && self.lines[0].is_empty() {
+            return false;
+        }
+        self.record(Edit::Other, true);
+        self.lines = vec![Vec::new()];
+        self.cursor = (0, 0);
+        true
+    }
+
+    fn backspace(&mut self) -> bool {
+        let (row, col) = self.cursor;
+        if col == 0 && row == 0 {
+            return false;
+        }
+        self.record(Edit::Delete, false);
+        if col > 0 {
+            self.lines[row].remove(col - 1);
+            self.cursor = (row, col - 1);
+        } else {
+            let line = self.lines.remove(row);
+            let len = self.lines[row - 1].len();
+            self.lines[row - 1].extend(line);
+            self.cursor = (row - 1, len);
+        }
+        true
+    }
+
+    fn delete(&mut self) -> bool {
+        let (row, col) = self.cursor;
+        if col == self.lines[row].len() && row + 1 == self.lines.len() {
+            return false;
+        }
+        self.record(Edit::Delete, false);
+        if col < self.lines[row].len() {
+            self.lines[row].remove(col);
+        } else {
+            let line = self.lines.remove(row + 1);
+            self.lines[row].extend(line);
+        }
+        true
+    }
+
+    // Visual rows as (line, first char, end char).
+    fn rows(&self) -> Vec<(usize, usize, usize)> {
+        rows(&self.lines, self.width)
+    }
+
+    fn cursor_row(&self, rows: &[(usize, usize, usize)]) -> usize {
+        let (line, col) = self.cursor;
+        rows.iter()
+            .rposition(|&(l, start, _)| l == line && start <= col)
+            .unwrap_or(0)
+    }
+
+    fn move_vertical(&mut self, delta: isize) -> bool {
+        let rows = self.rows();
+        let current = self.cursor_row(&rows);
+        let target = current.saturating_add_signed(delta).min(rows.len() - 1);
+        if target == current {
+            return false;
+        }
+        let offset = self.cursor.1 - rows[current].1;
+        let (line, start, end) = rows[target];
+        self.cursor = (line, (start + offset).min(end));
+        true
+    }
+
+    fn on_key(&mut self, key: KeyEvent, page: usize) -> (bool, bool) {
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        if std::mem::take(&mut self.selected) {
+            match key.code {
+                KeyCode::Backspace | KeyCode::Delete => {
+                    let changed = self.clear();
+                    return (true, changed);
+                }
+                KeyCode::Char(_) | KeyCode::Enter if !control => {
+                    self.clear();
+                }
+                _ => {}
+            }
+        }
+        let (row, col) = self.cursor;
+        match key.code {
+            KeyCode::Char('z') if control => (self.undo(), true),
+            KeyCode::Char('y') if control => (self.redo(), true),
+            KeyCode::Char('Z') if control => (self.redo(), true),
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert(c);
+                (true, true)
+            }
+            KeyCode::Enter => {
+                self.insert('\n');
+                (true, true)
+            }
+            KeyCode::Backspace => {
+                let changed = self.backspace();
+                (changed, changed)
+            }
+            KeyCode::Delete => {
+                let changed = self.delete();
+                (changed, changed)
+            }
+            KeyCode::Left if col > 0 => {
+                self.cursor = (row, col - 1);
+                (true, false)
+            }
+            KeyCode::Left if row > 0 => {
+                self.cursor = (row - 1, self.lines[row - 1].len());
+                (true, false)
+            }
+            KeyCode::Right if col < self.lines[row].len() => {
+                self.cursor = (row, col + 1);
+                (true, false)
+            }
+            KeyCode::Right if row + 1 < self.lines.len() => {
+                self.cursor = (row + 1, 0);
+                (true, false)
+            }
+            KeyCode::Home => {
+                self.cursor = (row, 0);
+                (true, false)
+            }
+            KeyCode::End => {
+                self.cursor = (row, self.lines[row].len());
+                (true, false)
+            }
+            KeyCode::Up => (self.move_vertical(-1), false),
+            KeyCode::Down => (self.move_vertical(1), false),
+            KeyCode::PageUp => (self.move_vertical(-(page as isize)), false),
+            KeyCode::PageDown => (self.move_vertical(page as isize), false),
+            _ => (false, false),
+        }
+    }
+}
+
+fn rows(lines: &[Vec<char>], width: usize) -> Vec<(usize, usize, usize)> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            rows.push((i, 0, 0));
+        }
+        for start in (0..line.len()).step_by(width) {
+            rows.push((i, start, (start + width).min(line.len())));
+        }
+    }
+    rows
+}
+
+fn gutter(attrs: &impl Attrs, lines: usize) -> u16 {
+    if attrs.flag("line_numbers") {
+        lines.to_string().len() as u16 + 1
+    } else {
+        0
+    }
+}
+
+pub(crate) struct Textarea;
+
+impl Widget for Textarea {
+    fn measure(&self, el: &El, width: u16, _ctx: &mut Ctx) -> u16 {
+        match el.attrs.int("height") {
+            Some(height) => height.max(0) as u16,
+            None => {
+                let value = el.attrs.text("value").unwrap_or_default();
+                let gutter = gutter(el.attrs, value.split('\n').count());
+                let rows = wrap(&value, width.saturating_sub(gutter)).len();
+                u16::try_from(rows).unwrap_or(u16::MAX)
+            }
+        }
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        if area.is_empty() {
+            return;
+        }
+        let focused = ctx.is_focused();
+        let state = ctx.state.get::<TextareaState>(&ctx.key);
+        state.bind(el.attrs.text("value"));
+
+        let gutter = gutter(el.attrs, state.lines.len());
+        let mut width = area.width.saturating_sub(gutter);
+        state.width = width as usize;
+        let mut rows = state.rows();
+        let scrollbar = rows.len() > area.height as usize;
+        if scrollbar {
+            width = width.saturating_sub(1);
+            state.width = width as usize;
+            rows = state.rows();
+        }
+        let cursor_row = state.cursor_row(&rows);
+        let cursor = state.cursor;
+        let reveal = std::mem::take(&mut state.reveal);
+        let selected = state.selected;
+        let lines = state.lines.clone();
+
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(area.height as usize, rows.len());
+        if reveal {
+            scroll.reveal(cursor_row, 1);
+        }
+        let offset = scroll.offset;
+
+        let number_style = Style::default().fg(Color::DarkGray);
+        for (y, &(line, start, end)) in rows
+            .iter()
+            .skip(offset)
+            .take(area.height as usize)
+            .enumerate()
+        {
+            let y = area.y + y as u16;
+            if gutter > 0 && start == 0 {
+                let number = format!("{:>1$}", line + 1, gutter as usize - 1);
+                buf.set_stringn(area.x, y, number, gutter as usize, number_style);
+            }
+            let text: String = lines[line][start..end].iter().collect();
+            let style = if selected {
+                selected_style(focused)
+            } else {
+                Style::default()
+            };
+            buf.set_stringn(area.x + gutter, y, text, width as usize, style);
+        }
+
+        // No cursor when the line numbers leave no room for text.
+        if focused && width > 0 && (offset..offset + area.height as usize).contains(&cursor_row) {
+            let x = (cursor.1 - rows[cursor_row].1).min(width.saturating_sub(1) as usize);
+            let position = (
+                area.x + gutter + x as u16,
+                area.y + (cursor_row - offset) as u16,
+            );
+            buf[position].set_style(Style::default().add_modifier(Modifier::REVERSED));
+        }
+
+        if scrollbar {
+            render_scrollbar(area, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        !el.attrs.flag("disabled")
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let page = state.get::<ScrollState>(&mounted.key).viewport;
+        edit(mounted, key, page.max(1), state)
+    }
+
+    fn value(&self, mounted: &Mounted, state: &mut StateMap) -> Option<AttrValue> {
+        Some(AttrValue::Text(
+            state.get::<TextareaState>(&mounted.key).text(),
+        ))
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        state.get::<ScrollState>(&mounted.key).scroll_by(delta)
+    }
+
+    fn call(&self, mounted: &Mounted, method: &str, state: &mut StateMap) -> bool {
+        let textarea = state.get::<TextareaState>(&mounted.key);
+        match method {
+            "select_all" => {
+                let last = textarea.lines.len() - 1;
+                textarea.cursor = (last, textarea.lines[last].len());
+                textarea.selected = true;
+                true
+            }
+            "clear" => {
+                if textarea.clear() {
+                    mounted
+                        .attrs
+                        .emit("onchange", Event::Change(AttrValue::Text(String::new())));
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn edit(mounted: &Mounted, key: KeyEvent, page: usize, state: &mut StateMap) -> bool {
+    let textarea = state.get::<TextareaState>(&mounted.key);
+    let (handled, changed) = textarea.on_key(key, page);
+    textarea.reveal |= handled;
+    if changed {
+        let text = textarea.text();
+        mounted
+            .attrs
+            .emit("onchange", Event::Change(AttrValue::Text(text)));
+    }
+    handled
+}
+
+// A single line `textarea`; Enter submits the form it is in.
+pub(crate) struct Input;
+
+impl Widget for Input {
+    fn measure(&self, _el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        1
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        if area.is_empty() {
+            return;
+        }
+        let focused = ctx.is_focused();
+        let state = ctx.state.get::<TextareaState>(&ctx.key);
+        state.bind(el.attrs.text("value").map(|value| value.replace('\n', " ")));
+        let line = &state.lines[0];
+        let col = state.cursor.1;
+
+        let style = if state.selected {
+            selected_style(focused)
+        } else {
+            Style::default()
+        };
+        let width = area.width as usize;
+        if line.is_empty() {
+            if let Some(placeholder) = el.attrs.text("placeholder") {
+                let style = Style::default().fg(Color::DarkGray);
+                buf.set_stringn(area.x, area.y, placeholder, width, style);
+            }
+        } else {
+            // Scrolls sideways just enough to keep the cursor in view.
+            let start = col.saturating_sub(width - 1);
+            let text: String = line[start..].iter().collect();
+            buf.set_stringn(area.x, area.y, text, width, style);
+        }
+        if focused {
+            let x = col.min(width - 1) as u16;
+            buf[(area.x + x, area.y)].set_style(Style::default().add_modifier(Modifier::REVERSED));
+        }
+    }
+
+    fn focusable(&self, el: &El) -> bool {
+        !el.attrs.flag("disabled")
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        match key.code {
+            KeyCode::Enter => mounted
+                .form
+                .as_deref()
+                .is_some_and(|form| form::submit(form, state)),
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown => false,
+            _ => edit(mounted, key, 1, state),
+        }
+    }
+
+    fn value(&self, mounted: &Mounted, state: &mut StateMap) -> Option<AttrValue> {
+        Textarea.value(mounted, state)
+    }
+
+    fn call(&self, mounted: &Mounted, method: &str, state: &mut StateMap) -> bool {
+        Textarea.call(mounted, method, state)
+    }
+}
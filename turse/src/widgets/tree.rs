@@ -0,0 +1,379 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::{Color, Style},
+};
+use turse_core::{AttrValue, Event, Node};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    selection::Selection,
+    state::StateMap,
+    task::{spawn, Task},
+    widget::{flatten, Attrs, Ctx, El, Mounted, Widget},
+    widgets::selected_style,
+};
+
+#[derive(Default)]
+struct TreeState {
+    expanded: HashSet<String>,
+    // The `expanded` attribute of every node as of the last render, by path.
+    bound: HashMap<String, bool>,
+    loading: HashMap<String, Loading>,
+    loaded: HashMap<String, Vec<Node>>,
+    rows: Vec<Row>,
+}
+
+// A `load` running on the runtime's executor, which leaves the nodes in the cell.
+struct Loading {
+    task: Task,
+    nodes: Rc<RefCell<Option<Vec<Node>>>>,
+}
+
+impl Drop for Loading {
+    fn drop(&mut self) {
+        self.task.cancel();
+    }
+}
+
+#[derive(Clone)]
+struct Row {
+    path: String,
+    label: String,
+    value: AttrValue,
+    depth: usize,
+    parent: Option<usize>,
+    // Whether each enclosing level still has siblings below, i.e. needs a `│` guide.
+    rails: Vec<bool>,
+    last: bool,
+    expandable: bool,
+    expanded: bool,
+    lazy: bool,
+    placeholder: bool,
+}
+
+fn tree_nodes(children: &[Node]) -> Vec<&Node> {
+    flatten(children)
+        .into_iter()
+        .filter(|node| matches!(node, Node::Element { tag, .. } if tag == "node"))
+        .collect()
+}
+
+fn label(attrs: &HashMap<String, AttrValue>, children: &[Node]) -> String {
+    if let Some(label) = attrs.text("label") {
+        return label;
+    }
+    flatten(children)
+        .into_iter()
+        .filter_map(|node| match node {
+            Node::Body(text) => Some(text.as_str()),
+            Node::Element { .. } => None,
+        })
+        .collect()
+}
+
+fn path(parent: &str, index: usize) -> String {
+    if parent.is_empty() {
+        index.to_string()
+    } else {
+        format!("{}/{}", parent, index)
+    }
+}
+
+impl TreeState {
+    fn collect(&mut self, nodes: &[&Node], parent: Option<usize>, rails: &[bool]) {
+        let prefix = match parent {
+            Some(i) => self.rows[i].path.clone(),
+            None => String::new(),
+        };
+        let depth = parent.map_or(0, |i| self.rows[i].depth + 1);
+        for (i, node) in nodes.iter().enumerate() {
+            let Node::Element {
+                attrs, children, ..
+            } = node
+            else {
+                continue;
+            };
+            let path = path(&prefix, i);
+            let bound = attrs.get("expanded").and_then(|v| v.as_bool());
+            if let Some(bound) = bound
+                && self.bound.insert(path.clone(), bound) != Some(bound)
+            {
+                if bound {
+                    self.expanded.insert(path.clone());
+                } else {
+                    self.expanded.remove(&path);
+                }
+            }
+
+            let lazy = attrs.flag("lazy");
+            let loaded = self.loaded.get(&path).cloned();
+            let own = match &loaded {
+                Some(nodes) if lazy => tree_nodes(nodes),
+                _ => tree_nodes(children),
+            };
+            let label = label(attrs, children);
+            let expandable = lazy || !own.is_empty();
+            let expanded = expandable && self.expanded.contains(&path);
+            let last = i + 1 == nodes.len();
+            let index = self.rows.len();
+            self.rows.push(Row {
+                path: path.clone(),
+                value: attrs
+                    .get("value")
+                    .cloned()
+                    .unwrap_or_else(|| AttrValue::Text(label.clone())),
+                label,
+                depth,
+                parent,
+                rails: rails.to_vec(),
+                last,
+                expandable,
+                expanded,
+                lazy,
+                placeholder: false,
+            });
+            if !expanded {
+                continue;
+            }
+
+            let mut inner = rails.to_vec();
+            if depth > 0 {
+                inner.push(!last);
+            }
+            if lazy && loaded.is_none() {
+                self.rows.push(Row {
+                    path: path.clone(),
+                    label: "Loading…".to_string(),
+                    value: AttrValue::Text(String::new()),
+                    depth: depth + 1,
+                    parent: Some(index),
+                    rails: inner,
+                    last: true,
+                    expandable: false,
+                    expanded: false,
+                    lazy: false,
+                    placeholder: true,
+                });
+            } else {
+                self.collect(&own, Some(index), &inner);
+            }
+        }
+    }
+
+    fn take_loaded(&mut self) {
+        let loaded = &mut self.loaded;
+        self.loading
+            .retain(|path, loading| match loading.nodes.borrow_mut().take() {
+                Some(nodes) => {
+                    loaded.insert(path.clone(), nodes);
+                    false
+                }
+                None => true,
+            });
+    }
+
+    fn set_expanded(&mut self, mounted: &Mounted, index: usize, expand: bool) -> bool {
+        let row = &self.rows[index];
+        if !row.expandable || row.expanded == expand {
+            return false;
+        }
+        let (path, value) = (row.path.clone(), row.value.clone());
+        if expand {
+            let load = mounted.attrs.get("load");
+            if let Some(AttrValue::Load(load)) = load
+                && row.lazy
+                && !self.loaded.contains_key(&path)
+                && !self.loading.contains_key(&path)
+            {
+                let nodes = Rc::new(RefCell::new(None));
+                let (future, done) = (load.call(&value), nodes.clone());
+                let task = spawn(async move {
+                    *done.borrow_mut() = Some(future.await);
+                });
+                self.loading.insert(path.clone(), Loading { task, nodes });
+            }
+            self.expanded.insert(path);
+            mounted.attrs.emit("onexpand", Event::Change(value));
+        } else {
+            self.expanded.remove(&path);
+            mounted.attrs.emit("oncollapse", Event::Change(value));
+        }
+        true
+    }
+}
+
+fn guides(row: &Row) -> String {
+    let mut prefix = String::new();
+    for &rail in &row.rails {
+        prefix.push_str(if rail { "│  " } else { "   " });
+    }
+    if row.depth > 0 {
+        prefix.push_str(if row.last { "└─ " } else { "├─ " });
+    }
+    prefix
+}
+
+fn select(mounted: &Mounted, state: &mut StateMap, index: usize) -> bool {
+    state.get::<Selection>(&mounted.key).select(index) && selected(mounted, state, index)
+}
+
+fn selected(mounted: &Mounted, state: &mut StateMap, index: usize) -> bool {
+    state.get::<ScrollState>(&mounted.key).reveal(index, 1);
+    let row = &state.get::<TreeState>(&mounted.key).rows[index];
+    if !row.placeholder {
+        mounted
+            .attrs
+            .emit("onselect", Event::Change(row.value.clone()));
+    }
+    true
+}
+
+pub(crate) struct Tree;
+
+impl Widget for Tree {
+    // Grows with the rows expanded by key or mouse as well as by the `expanded` attributes.
+    fn measure(&self, el: &El, _width: u16, ctx: &mut Ctx) -> u16 {
+        if let Some(height) = el.attrs.int("height") {
+            return height.max(0) as u16;
+        }
+        let tree = ctx.state.get::<TreeState>(&ctx.key);
+        tree.take_loaded();
+        tree.rows.clear();
+        tree.collect(&tree_nodes(el.children), None, &[]);
+        u16::try_from(tree.rows.len()).unwrap_or(u16::MAX)
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let focused = ctx.is_focused();
+        let tree = ctx.state.get::<TreeState>(&ctx.key);
+        tree.take_loaded();
+        tree.rows.clear();
+        tree.collect(&tree_nodes(el.children), None, &[]);
+        let rows = tree.rows.clone();
+
+        let selection = ctx.state.get::<Selection>(&ctx.key);
+        selection.clamp(rows.len());
+        let cursor = selection.cursor;
+
+        let scrollbar = rows.len() > area.height as usize;
+        let viewport = if scrollbar {
+            Rect {
+                width: area.width.saturating_sub(1),
+                ..area
+            }
+        } else {
+            area
+        };
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(viewport.height as usize, rows.len());
+        let offset = scroll.offset;
+
+        let guide_style = Style::default().fg(Color::DarkGray);
+        for (y, (i, row)) in rows
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(viewport.height as usize)
+            .enumerate()
+        {
+            let line = Rect {
+                y: viewport.y + y as u16,
+                height: 1,
+                ..viewport
+            };
+            let width = line.width as usize;
+            let (x, _) = buf.set_stringn(line.x, line.y, guides(row), width, guide_style);
+            let marker = match (row.expandable, row.expanded) {
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+                (false, _) => "  ",
+            };
+            let remaining = (line.right() - x) as usize;
+            let (x, _) = buf.set_stringn(x, line.y, marker, remaining, Style::default());
+            let style = if row.placeholder {
+                guide_style
+            } else {
+                Style::default()
+            };
+            let remaining = (line.right() - x) as usize;
+            buf.set_stringn(x, line.y, &row.label, remaining, style);
+            if cursor == Some(i) {
+                buf.set_style(line, selected_style(focused));
+            }
+        }
+
+        if scrollbar {
+            render_scrollbar(area, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let page = state.get::<ScrollState>(&mounted.key).viewport;
+        let cursor = state.get::<Selection>(&mounted.key).cursor;
+        let tree = state.get::<TreeState>(&mounted.key);
+        let count = tree.rows.len();
+
+        let Some(index) = cursor.filter(|&i| i < count) else {
+            return state
+                .get::<Selection>(&mounted.key)
+                .on_key(key, count, page)
+                && selected(mounted, state, 0);
+        };
+        let row = tree.rows[index].clone();
+        match key.code {
+            KeyCode::Right if row.expandable && !row.expanded => {
+                tree.set_expanded(mounted, index, true)
+            }
+            KeyCode::Right if row.expanded && index + 1 < count => {
+                select(mounted, state, index + 1)
+            }
+            KeyCode::Left if row.expanded => tree.set_expanded(mounted, index, false),
+            KeyCode::Left => match row.parent {
+                Some(parent) => select(mounted, state, parent),
+                None => false,
+            },
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let toggled = tree.set_expanded(mounted, index, !row.expanded);
+                if key.code == KeyCode::Enter && !row.placeholder {
+                    mounted.attrs.emit("onactivate", Event::Change(row.value));
+                    return true;
+                }
+                toggled
+            }
+            _ => {
+                let mut selection = Selection {
+                    cursor: Some(index),
+                };
+                selection.on_key(key, count, page)
+                    && select(mounted, state, selection.cursor.unwrap_or(0))
+            }
+        }
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        state.get::<ScrollState>(&mounted.key).scroll_by(delta)
+    }
+
+    fn on_click(&self, mounted: &Mounted, position: Position, state: &mut StateMap) -> bool {
+        let index = state.get::<ScrollState>(&mounted.key).offset + position.y as usize;
+        let tree = state.get::<TreeState>(&mounted.key);
+        let Some(row) = tree.rows.get(index) else {
+            return false;
+        };
+        let expanded = row.expanded;
+        let toggled = tree.set_expanded(mounted, index, !expanded);
+        select(mounted, state, index) || toggled
+    }
+}
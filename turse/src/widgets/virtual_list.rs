@@ -0,0 +1,123 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::{Position, Rect, Size},
+};
+use turse_core::{AttrValue, Event};
+
+use crate::{
+    scroll::{render_scrollbar, ScrollState},
+    selection::Selection,
+    state::StateMap,
+    widget::{child_key, render_visible, Attrs, Ctx, El, Mounted, Widget},
+    widgets::selected_style,
+};
+
+const OVERSCAN: usize = 3;
+
+fn count(attrs: &impl Attrs) -> usize {
+    attrs.int("count").unwrap_or(0).max(0) as usize
+}
+
+fn row_height(attrs: &impl Attrs) -> usize {
+    attrs.int("row_height").unwrap_or(1).max(1) as usize
+}
+
+pub(crate) struct VirtualList;
+
+impl Widget for VirtualList {
+    fn measure(&self, el: &El, _width: u16, _ctx: &mut Ctx) -> u16 {
+        match el.attrs.int("height") {
+            Some(height) => height.max(0) as u16,
+            None => count(el.attrs)
+                .saturating_mul(row_height(el.attrs))
+                .min(u16::MAX as usize) as u16,
+        }
+    }
+
+    fn render(&self, el: &El, area: Rect, buf: &mut Buffer, ctx: &mut Ctx) {
+        let count = count(el.attrs);
+        let row_height = row_height(el.attrs);
+        let overscan = el
+            .attrs
+            .int("overscan")
+            .map_or(OVERSCAN, |n| n.max(0) as usize);
+        let content = count.saturating_mul(row_height);
+        let scrollbar = content > area.height as usize;
+        let viewport = Rect {
+            width: area.width.saturating_sub(scrollbar as u16),
+            ..area
+        };
+
+        let scroll = ctx.state.get::<ScrollState>(&ctx.key);
+        scroll.resize(area.height as usize, content);
+        let offset = scroll.offset;
+        let selection = ctx.state.get::<Selection>(&ctx.key);
+        selection.clamp(count);
+        let selected = selection.cursor;
+
+        let first = offset / row_height;
+        let visible = area.height as usize / row_height + 1;
+        let start = first.saturating_sub(overscan);
+        let end = (first + visible + overscan).min(count);
+        let window = ((end - start) * row_height).min(u16::MAX as usize) as u16;
+        let style = selected_style(ctx.is_focused());
+
+        let Some(AttrValue::Render(render)) = el.attrs.get("render") else {
+            return;
+        };
+        ctx.scrolled(
+            viewport,
+            buf,
+            Size::new(viewport.width, window),
+            Position::new(0, (offset - start * row_height) as u16),
+            start * row_height,
+            |area, buf, ctx| {
+                for index in start..end {
+                    let row = Rect::new(
+                        area.x,
+                        ((index - start) * row_height) as u16,
+                        area.width,
+                        row_height as u16,
+                    );
+                    let node = render.call(index);
+                    render_visible(&node, child_key(&ctx.key, index), row, buf, ctx);
+                    if selected == Some(index) {
+                        buf.set_style(row, style);
+                    }
+                }
+            },
+        );
+
+        if scrollbar {
+            render_scrollbar(area, buf, ctx.state.get::<ScrollState>(&ctx.key));
+        }
+    }
+
+    fn focusable(&self, _el: &El) -> bool {
+        true
+    }
+
+    fn on_key(&self, mounted: &Mounted, key: KeyEvent, state: &mut StateMap) -> bool {
+        let row_height = row_height(&mounted.attrs);
+        let page = state.get::<ScrollState>(&mounted.key).viewport / row_height;
+        let selection = state.get::<Selection>(&mounted.key);
+        if !selection.on_key(key, count(&mounted.attrs), page) {
+            return false;
+        }
+        let Some(index) = selection.cursor else {
+            return false;
+        };
+        state
+            .get::<ScrollState>(&mounted.key)
+            .reveal(index * row_height, row_height);
+        mounted.attrs.emit("onselect", Event::Select(index));
+        true
+    }
+
+    fn on_scroll(&self, mounted: &Mounted, delta: isize, state: &mut StateMap) -> bool {
+        state
+            .get::<ScrollState>(&mounted.key)
+            .scroll_by(delta * row_height(&mounted.attrs) as isize)
+    }
+}